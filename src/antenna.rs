@@ -0,0 +1,123 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+
+/// Shape of the antenna's main lobe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum MainLobe {
+    /// Smooth Gaussian roll-off with no nulls.
+    #[default]
+    Gaussian,
+    /// `sinc²` pattern of a uniformly illuminated aperture, with a null at the lobe edge.
+    Sinc,
+}
+
+/// Horizontal antenna gain pattern, normalized to 1.0 on boresight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AntennaPattern {
+    /// Half-power (-3 dB) beam width in degrees.
+    pub beam_width: f64,
+    pub main_lobe: MainLobe,
+    /// Peak sidelobe level relative to boresight, in dB (negative).
+    pub sidelobe_level_db: f64,
+    /// Degrees off boresight past which only the back lobe remains.
+    pub sidelobe_extent: f64,
+    /// Gain everywhere outside the sidelobes, in dB (negative).
+    pub backlobe_level_db: f64,
+}
+
+impl Default for AntennaPattern {
+    fn default() -> Self {
+        Self {
+            beam_width: 3.0,
+            main_lobe: MainLobe::default(),
+            sidelobe_level_db: -25.0,
+            sidelobe_extent: 30.0,
+            backlobe_level_db: -60.0,
+        }
+    }
+}
+
+/// `sin(x)/x` reaches half power at this argument.
+const SINC_HALF_POWER: f64 = 1.391_557_4;
+
+impl AntennaPattern {
+    pub fn new(beam_width: f64) -> Self {
+        Self {
+            beam_width,
+            ..Self::default()
+        }
+    }
+
+    /// Degrees off boresight where the main lobe ends and the sidelobes begin.
+    pub fn main_lobe_half_width(&self) -> f64 {
+        match self.main_lobe {
+            // Where the Gaussian falls to the first sidelobe level
+            MainLobe::Gaussian => {
+                let level = db_to_linear(self.sidelobe_level_db);
+                self.beam_width / 2.0 * (level.ln() / 0.5f64.ln()).sqrt()
+            }
+            // First null of sinc²
+            MainLobe::Sinc => self.beam_width / 2.0 * std::f64::consts::PI / SINC_HALF_POWER,
+        }
+    }
+
+    /// Power gain relative to boresight for a target `off_boresight` degrees away.
+    pub fn gain(&self, off_boresight: f64) -> f64 {
+        let off = off_boresight.abs();
+        let edge = self.main_lobe_half_width();
+        if off <= edge {
+            return match self.main_lobe {
+                MainLobe::Gaussian => 0.5f64.powf((off / (self.beam_width / 2.0)).powi(2)),
+                MainLobe::Sinc => {
+                    let x = SINC_HALF_POWER * off / (self.beam_width / 2.0);
+                    if x == 0.0 { 1.0 } else { (x.sin() / x).powi(2) }
+                }
+            };
+        }
+        if off <= self.sidelobe_extent {
+            // Sidelobe envelope falls linearly in dB down to the back lobe
+            let fraction = (off - edge) / (self.sidelobe_extent - edge);
+            let envelope_db = self.sidelobe_level_db
+                + (self.backlobe_level_db - self.sidelobe_level_db) * fraction;
+            return db_to_linear(envelope_db.max(self.backlobe_level_db));
+        }
+        db_to_linear(self.backlobe_level_db)
+    }
+}
+
+pub fn db_to_linear(db: f64) -> f64 {
+    10f64.powf(db / 10.0)
+}
+
+pub fn linear_to_db(linear: f64) -> f64 {
+    10.0 * linear.log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_half_power_at_half_beam_width() {
+        for main_lobe in [MainLobe::Gaussian, MainLobe::Sinc] {
+            let pattern = AntennaPattern {
+                main_lobe,
+                ..AntennaPattern::new(4.0)
+            };
+            assert_eq!(pattern.gain(0.0), 1.0);
+            assert!((pattern.gain(2.0) - 0.5).abs() < 1e-6);
+            assert!((pattern.gain(-2.0) - 0.5).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_sidelobes_below_main_lobe() {
+        let pattern = AntennaPattern::new(3.0);
+        let edge = pattern.main_lobe_half_width();
+        let first_sidelobe = pattern.gain(edge + 0.01);
+        assert!(first_sidelobe <= db_to_linear(pattern.sidelobe_level_db) + 1e-9);
+        assert!(pattern.gain(20.0) < first_sidelobe);
+        assert_eq!(pattern.gain(90.0), db_to_linear(pattern.backlobe_level_db));
+    }
+}
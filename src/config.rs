@@ -0,0 +1,721 @@
+use crate::antenna::{AntennaPattern, MainLobe};
+use crate::detection::{DetectionModel, MeasurementModel, Swerling};
+use crate::own_ship::{Orientation, OwnShip};
+use crate::radar::{BearingConvention, ObjectType, RadarWidget};
+use crate::scenario::RadarSettings;
+use crate::tracker::{Association, Tracker};
+use crate::zone::{Zone, ZoneSpec};
+use clap::{Args, Parser};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, error::Error, fmt, fs, io};
+
+/// Errors that can occur while building the configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file exists but could not be read.
+    Io(PathBuf, io::Error),
+    /// The config file is not valid TOML or has unknown keys.
+    Parse(PathBuf, toml::de::Error),
+    /// A setting has a value that cannot work, e.g. a zero tick rate.
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, e) => write!(f, "Failed to read {}: {e}", path.display()),
+            ConfigError::Parse(path, e) => write!(f, "Failed to parse {}: {e}", path.display()),
+            ConfigError::Invalid(msg) => write!(f, "Invalid configuration: {msg}"),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// Command-line interface for the simulator.
+#[derive(Debug, Default, Parser)]
+#[command(version, about = "A terminal radar simulator")]
+pub struct Cli {
+    /// Read settings from this file instead of the default config location.
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub settings: Settings,
+}
+
+/// One layer of settings; unset fields fall through to the layer below.
+///
+/// The same keys are accepted on the command line (`--tick-rate 20`) and in
+/// the config file (`tick_rate = 20`).
+#[derive(Debug, Clone, Default, Args, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Settings {
+    /// Frames rendered per second.
+    #[arg(long)]
+    pub frame_rate: Option<f64>,
+    /// Simulation ticks per second.
+    #[arg(long)]
+    pub tick_rate: Option<f64>,
+    /// Radar coverage in range units.
+    #[arg(long)]
+    pub max_range: Option<f64>,
+    /// Display ranges stepped through with R, comma separated.
+    #[arg(long, value_delimiter = ',')]
+    pub range_scales: Option<Vec<f64>>,
+    /// Antenna rotation in degrees per second.
+    #[arg(long)]
+    pub rotation_rate: Option<f64>,
+    /// Horizontal half-power beam width in degrees.
+    #[arg(long)]
+    pub beam_width: Option<f64>,
+    /// Main lobe shape of the antenna pattern.
+    #[arg(long, value_enum)]
+    pub main_lobe: Option<MainLobe>,
+    /// Peak sidelobe level relative to the main lobe, in dB.
+    #[arg(long, allow_negative_numbers = true)]
+    pub sidelobe_level: Option<f64>,
+    /// SNR in dB of a 1 m² target at max range on boresight.
+    #[arg(long, allow_negative_numbers = true)]
+    pub reference_snr: Option<f64>,
+    /// Chance that noise alone is detected on a single look.
+    #[arg(long)]
+    pub false_alarm_probability: Option<f64>,
+    /// Fluctuation model for every target instead of the per-type default.
+    #[arg(long, value_enum)]
+    pub swerling: Option<Swerling>,
+    /// Standard deviation of plot range error, in range units.
+    #[arg(long)]
+    pub range_noise: Option<f64>,
+    /// Standard deviation of plot bearing error, in degrees.
+    #[arg(long)]
+    pub bearing_noise: Option<f64>,
+    /// Range resolution cell, in range units.
+    #[arg(long)]
+    pub range_bin: Option<f64>,
+    /// Azimuth resolution cell, in degrees.
+    #[arg(long)]
+    pub azimuth_cell: Option<f64>,
+    /// Tracker acceleration noise density, in range units² per s³.
+    #[arg(long)]
+    pub process_noise: Option<f64>,
+    /// Largest squared Mahalanobis distance at which a plot may update a track.
+    #[arg(long)]
+    pub gate: Option<f64>,
+    /// How gated plots are assigned to tracks.
+    #[arg(long, value_enum)]
+    pub association: Option<Association>,
+    /// Looks with a plot needed to confirm a new track (M of M-of-N).
+    #[arg(long)]
+    pub confirm_hits: Option<u32>,
+    /// Looks a new track gets to confirm (N of M-of-N).
+    #[arg(long)]
+    pub confirm_looks: Option<u32>,
+    /// Consecutive missed looks before a track is dropped.
+    #[arg(long)]
+    pub drop_misses: Option<u32>,
+    /// Minutes of travel shown by velocity leaders.
+    #[arg(long)]
+    pub leader_minutes: Option<f64>,
+    /// Past plot positions shown in each track's trail.
+    #[arg(long)]
+    pub trail_length: Option<usize>,
+    /// Alert on tracks passing closer than this, in range units.
+    #[arg(long)]
+    pub cpa_limit: Option<f64>,
+    /// Only alert when the closest approach is at most this many seconds away.
+    #[arg(long)]
+    pub tcpa_limit: Option<f64>,
+    /// Own ship heading in degrees, 0 = east, counter-clockwise.
+    #[arg(long, allow_negative_numbers = true)]
+    pub own_heading: Option<f64>,
+    /// Own ship speed in range units per second.
+    #[arg(long)]
+    pub own_speed: Option<f64>,
+    /// Own ship rate of turn in degrees per second, positive to port.
+    #[arg(long, allow_negative_numbers = true)]
+    pub own_turn_rate: Option<f64>,
+    /// Which direction is at the top of the display.
+    #[arg(long, value_enum)]
+    pub orientation: Option<Orientation>,
+    /// Move own ship across a ground-fixed picture instead of keeping it centered.
+    #[arg(long)]
+    pub true_motion: Option<bool>,
+    /// How bearings are labelled and read out; settings and scenarios always use math angles.
+    #[arg(long, value_enum)]
+    pub bearing_convention: Option<BearingConvention>,
+    /// Range rings drawn across the display range.
+    #[arg(long)]
+    pub range_rings: Option<u32>,
+    /// Degrees between bearing lines.
+    #[arg(long)]
+    pub bearing_spacing: Option<f64>,
+    /// Height of a terminal cell over its width; raise it if rings look tall.
+    #[arg(long)]
+    pub cell_aspect: Option<f64>,
+    /// Seconds for a track to fade after its last update.
+    #[arg(long)]
+    pub fade_duration: Option<f64>,
+    /// Seconds between random spawns.
+    #[arg(long)]
+    pub spawn_interval: Option<f64>,
+    /// Relative spawn weights, e.g. `aircraft=4,ship=2,weather=0`.
+    #[arg(long)]
+    pub spawn_mix: Option<SpawnMix>,
+    /// Seed for the simulation RNG; random when unset.
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Load a scenario file.
+    #[arg(long, value_name = "PATH")]
+    pub scenario: Option<PathBuf>,
+    /// Guard and exclusion zones; config file only.
+    #[arg(skip)]
+    pub zones: Option<Vec<ZoneSpec>>,
+}
+
+/// Relative weights used to pick the type of each random spawn.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpawnMix {
+    pub aircraft: f64,
+    pub ship: f64,
+    pub unknown: f64,
+    pub hostile: f64,
+    pub generic: f64,
+    pub weather: f64,
+}
+
+impl Default for SpawnMix {
+    fn default() -> Self {
+        Self {
+            aircraft: 4.0,
+            ship: 2.0,
+            unknown: 1.0,
+            hostile: 1.0,
+            generic: 1.0,
+            weather: 1.0,
+        }
+    }
+}
+
+impl SpawnMix {
+    pub fn weights(&self) -> [(ObjectType, f64); 6] {
+        [
+            (ObjectType::AirCraft, self.aircraft),
+            (ObjectType::Ship, self.ship),
+            (ObjectType::Unknown, self.unknown),
+            (ObjectType::Hostile, self.hostile),
+            (ObjectType::Generic, self.generic),
+            (ObjectType::Weather, self.weather),
+        ]
+    }
+}
+
+impl FromStr for SpawnMix {
+    type Err = String;
+
+    /// Parse `type=weight` pairs; types left out keep their default weight.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut mix = SpawnMix::default();
+        for pair in s.split(',').filter(|pair| !pair.trim().is_empty()) {
+            let (name, weight) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected type=weight, got {pair:?}"))?;
+            let weight: f64 = weight
+                .trim()
+                .parse()
+                .map_err(|e| format!("invalid weight for {name}: {e}"))?;
+            let slot = match name.trim() {
+                "aircraft" => &mut mix.aircraft,
+                "ship" => &mut mix.ship,
+                "unknown" => &mut mix.unknown,
+                "hostile" => &mut mix.hostile,
+                "generic" => &mut mix.generic,
+                "weather" => &mut mix.weather,
+                other => return Err(format!("unknown object type {other:?}")),
+            };
+            *slot = weight;
+        }
+        Ok(mix)
+    }
+}
+
+impl Settings {
+    /// Read the settings file at `path`, or the default one when `path` is `None`.
+    ///
+    /// A missing default file is not an error; a missing explicit one is.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match Self::default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !required => {
+                return Ok(Self::default());
+            }
+            Err(e) => return Err(ConfigError::Io(path, e)),
+        };
+        toml::from_str(&text).map_err(|e| ConfigError::Parse(path, e))
+    }
+
+    /// `$XDG_CONFIG_HOME/tui-radar-sim/config.toml`, falling back to `~/.config`.
+    pub fn default_path() -> Option<PathBuf> {
+        let base = env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(base.join("tui-radar-sim").join("config.toml"))
+    }
+
+    /// Layer `other` on top of `self`: every field set in `other` wins.
+    pub fn merge(self, other: Settings) -> Settings {
+        Settings {
+            frame_rate: other.frame_rate.or(self.frame_rate),
+            tick_rate: other.tick_rate.or(self.tick_rate),
+            max_range: other.max_range.or(self.max_range),
+            range_scales: other.range_scales.or(self.range_scales),
+            rotation_rate: other.rotation_rate.or(self.rotation_rate),
+            beam_width: other.beam_width.or(self.beam_width),
+            main_lobe: other.main_lobe.or(self.main_lobe),
+            sidelobe_level: other.sidelobe_level.or(self.sidelobe_level),
+            reference_snr: other.reference_snr.or(self.reference_snr),
+            false_alarm_probability: other
+                .false_alarm_probability
+                .or(self.false_alarm_probability),
+            swerling: other.swerling.or(self.swerling),
+            range_noise: other.range_noise.or(self.range_noise),
+            bearing_noise: other.bearing_noise.or(self.bearing_noise),
+            range_bin: other.range_bin.or(self.range_bin),
+            azimuth_cell: other.azimuth_cell.or(self.azimuth_cell),
+            process_noise: other.process_noise.or(self.process_noise),
+            gate: other.gate.or(self.gate),
+            association: other.association.or(self.association),
+            confirm_hits: other.confirm_hits.or(self.confirm_hits),
+            confirm_looks: other.confirm_looks.or(self.confirm_looks),
+            drop_misses: other.drop_misses.or(self.drop_misses),
+            leader_minutes: other.leader_minutes.or(self.leader_minutes),
+            trail_length: other.trail_length.or(self.trail_length),
+            cpa_limit: other.cpa_limit.or(self.cpa_limit),
+            tcpa_limit: other.tcpa_limit.or(self.tcpa_limit),
+            own_heading: other.own_heading.or(self.own_heading),
+            own_speed: other.own_speed.or(self.own_speed),
+            own_turn_rate: other.own_turn_rate.or(self.own_turn_rate),
+            orientation: other.orientation.or(self.orientation),
+            true_motion: other.true_motion.or(self.true_motion),
+            bearing_convention: other.bearing_convention.or(self.bearing_convention),
+            range_rings: other.range_rings.or(self.range_rings),
+            bearing_spacing: other.bearing_spacing.or(self.bearing_spacing),
+            cell_aspect: other.cell_aspect.or(self.cell_aspect),
+            fade_duration: other.fade_duration.or(self.fade_duration),
+            spawn_interval: other.spawn_interval.or(self.spawn_interval),
+            spawn_mix: other.spawn_mix.or(self.spawn_mix),
+            seed: other.seed.or(self.seed),
+            scenario: other.scenario.or(self.scenario),
+            zones: other.zones.or(self.zones),
+        }
+    }
+}
+
+impl From<&RadarSettings> for Settings {
+    fn from(radar: &RadarSettings) -> Self {
+        Settings {
+            max_range: radar.max_range,
+            range_scales: radar.range_scales.clone(),
+            rotation_rate: radar.rotation_rate,
+            fade_duration: radar.fade_duration,
+            own_heading: radar.own_heading,
+            own_speed: radar.own_speed,
+            own_turn_rate: radar.own_turn_rate,
+            ..Settings::default()
+        }
+    }
+}
+
+/// Fully resolved settings used to build the simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub frame_rate: f64,
+    pub tick_rate: f64,
+    pub max_range: f64,
+    pub range_scales: Vec<f64>,
+    pub rotation_rate: f64,
+    pub antenna: AntennaPattern,
+    pub detection: DetectionModel,
+    pub measurement: MeasurementModel,
+    pub process_noise: f64,
+    pub gate: f64,
+    pub association: Association,
+    pub confirm_hits: u32,
+    pub confirm_looks: u32,
+    pub drop_misses: u32,
+    pub leader_minutes: f64,
+    pub trail_length: usize,
+    pub cpa_limit: f64,
+    pub tcpa_limit: f64,
+    pub zones: Vec<Zone>,
+    pub own_ship: OwnShip,
+    pub orientation: Orientation,
+    pub true_motion: bool,
+    pub bearing_convention: BearingConvention,
+    pub range_rings: u32,
+    pub bearing_spacing: f64,
+    pub cell_aspect: f64,
+    pub fade_duration: f64,
+    pub spawn_interval: f64,
+    pub spawn_mix: SpawnMix,
+    pub seed: Option<u64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::resolve(Settings::default()).expect("defaults are valid")
+    }
+}
+
+impl Config {
+    /// Fill unset fields with defaults and reject values that cannot work.
+    pub fn resolve(settings: Settings) -> Result<Self, ConfigError> {
+        let max_range = settings.max_range.unwrap_or(1000.0);
+        let rotation_rate = settings
+            .rotation_rate
+            .unwrap_or(RadarWidget::DEGREES_PER_SECOND);
+        let zones = match &settings.zones {
+            Some(specs) => specs
+                .iter()
+                .enumerate()
+                .map(|(i, spec)| {
+                    spec.build().map_err(|message| {
+                        ConfigError::Invalid(format!("zones[{i}] ({}): {message}", spec.name))
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            None => vec![Zone::danger_close(max_range * 0.1)],
+        };
+        let config = Config {
+            frame_rate: settings.frame_rate.unwrap_or(30.0),
+            tick_rate: settings.tick_rate.unwrap_or(15.0),
+            max_range,
+            range_scales: settings
+                .range_scales
+                .unwrap_or_else(|| RadarWidget::default_range_scales(max_range)),
+            rotation_rate,
+            antenna: AntennaPattern {
+                main_lobe: settings.main_lobe.unwrap_or_default(),
+                sidelobe_level_db: settings
+                    .sidelobe_level
+                    .unwrap_or(AntennaPattern::default().sidelobe_level_db),
+                ..AntennaPattern::new(settings.beam_width.unwrap_or(RadarWidget::BEAM_WIDTH))
+            },
+            detection: DetectionModel {
+                reference_snr_db: settings
+                    .reference_snr
+                    .unwrap_or(DetectionModel::default().reference_snr_db),
+                reference_range: max_range,
+                false_alarm_probability: settings
+                    .false_alarm_probability
+                    .unwrap_or(DetectionModel::default().false_alarm_probability),
+                stc_range: max_range / 4.0,
+                swerling: settings.swerling,
+            },
+            measurement: {
+                let defaults = MeasurementModel::default();
+                MeasurementModel {
+                    range_noise: settings.range_noise.unwrap_or(defaults.range_noise),
+                    bearing_noise: settings.bearing_noise.unwrap_or(defaults.bearing_noise),
+                    range_bin: settings.range_bin.unwrap_or(defaults.range_bin),
+                    azimuth_cell: settings.azimuth_cell.unwrap_or(defaults.azimuth_cell),
+                }
+            },
+            process_noise: settings
+                .process_noise
+                .unwrap_or(Tracker::default().process_noise),
+            gate: settings.gate.unwrap_or(Tracker::default().gate),
+            association: settings.association.unwrap_or_default(),
+            confirm_hits: settings
+                .confirm_hits
+                .unwrap_or(Tracker::default().confirm_hits),
+            confirm_looks: settings
+                .confirm_looks
+                .unwrap_or(Tracker::default().confirm_looks),
+            drop_misses: settings
+                .drop_misses
+                .unwrap_or(Tracker::default().drop_misses),
+            leader_minutes: settings.leader_minutes.unwrap_or(0.25),
+            trail_length: settings
+                .trail_length
+                .unwrap_or(Tracker::default().history_length),
+            // Alert on anything heading inside the danger ring
+            cpa_limit: settings.cpa_limit.unwrap_or(max_range * 0.1),
+            tcpa_limit: settings.tcpa_limit.unwrap_or(120.0),
+            zones,
+            own_ship: OwnShip::default()
+                .with_motion(
+                    settings.own_heading.unwrap_or(OwnShip::default().heading),
+                    settings.own_speed.unwrap_or(0.0),
+                )
+                .with_turn_rate(settings.own_turn_rate.unwrap_or(0.0)),
+            orientation: settings.orientation.unwrap_or_default(),
+            true_motion: settings.true_motion.unwrap_or(false),
+            bearing_convention: settings.bearing_convention.unwrap_or_default(),
+            range_rings: settings.range_rings.unwrap_or(4),
+            bearing_spacing: settings.bearing_spacing.unwrap_or(30.0),
+            cell_aspect: settings.cell_aspect.unwrap_or(RadarWidget::CELL_ASPECT),
+            fade_duration: settings
+                .fade_duration
+                .unwrap_or_else(|| RadarWidget::default_fade_duration(rotation_rate)),
+            spawn_interval: settings.spawn_interval.unwrap_or(5.0),
+            spawn_mix: settings.spawn_mix.unwrap_or_default(),
+            seed: settings.seed,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let positive = [
+            ("frame_rate", self.frame_rate),
+            ("tick_rate", self.tick_rate),
+            ("max_range", self.max_range),
+            ("rotation_rate", self.rotation_rate),
+            ("beam_width", self.antenna.beam_width),
+            ("fade_duration", self.fade_duration),
+            ("spawn_interval", self.spawn_interval),
+            ("range_bin", self.measurement.range_bin),
+            ("azimuth_cell", self.measurement.azimuth_cell),
+            ("process_noise", self.process_noise),
+            ("cpa_limit", self.cpa_limit),
+            ("tcpa_limit", self.tcpa_limit),
+            ("gate", self.gate),
+            ("cell_aspect", self.cell_aspect),
+        ];
+        let scales = self
+            .range_scales
+            .iter()
+            .map(|&scale| ("range_scales", scale));
+        for (name, value) in positive.into_iter().chain(scales) {
+            if !(value > 0.0 && value.is_finite()) {
+                return Err(ConfigError::Invalid(format!(
+                    "{name} must be a positive number, got {value}"
+                )));
+            }
+        }
+        let noise = [
+            ("range_noise", self.measurement.range_noise),
+            ("bearing_noise", self.measurement.bearing_noise),
+            ("leader_minutes", self.leader_minutes),
+            ("own_speed", self.own_ship.speed),
+        ];
+        for (name, value) in noise {
+            if !(value >= 0.0 && value.is_finite()) {
+                return Err(ConfigError::Invalid(format!(
+                    "{name} must be zero or positive, got {value}"
+                )));
+            }
+        }
+        for (name, value) in [
+            ("own_heading", self.own_ship.heading),
+            ("own_turn_rate", self.own_ship.turn_rate),
+        ] {
+            if !value.is_finite() {
+                return Err(ConfigError::Invalid(format!(
+                    "{name} must be a number, got {value}"
+                )));
+            }
+        }
+        if self.confirm_hits == 0 || self.confirm_hits > self.confirm_looks {
+            return Err(ConfigError::Invalid(format!(
+                "confirm_hits must be between 1 and confirm_looks ({}), got {}",
+                self.confirm_looks, self.confirm_hits
+            )));
+        }
+        if self.range_rings == 0 {
+            return Err(ConfigError::Invalid(
+                "range_rings must be at least 1".to_string(),
+            ));
+        }
+        if !(self.bearing_spacing > 0.0 && self.bearing_spacing <= 360.0) {
+            return Err(ConfigError::Invalid(format!(
+                "bearing_spacing must be between 0 and 360 degrees, got {}",
+                self.bearing_spacing
+            )));
+        }
+        // A sweep of a full turn or more per tick would skip over targets
+        if self.rotation_rate / self.tick_rate >= 360.0 {
+            return Err(ConfigError::Invalid(format!(
+                "rotation_rate ({}) must be under 360 degrees per tick at tick_rate {}",
+                self.rotation_rate, self.tick_rate
+            )));
+        }
+        if self.drop_misses == 0 {
+            return Err(ConfigError::Invalid(
+                "drop_misses must be at least 1".to_string(),
+            ));
+        }
+        let pfa = self.detection.false_alarm_probability;
+        if !(pfa > 0.0 && pfa < 1.0) {
+            return Err(ConfigError::Invalid(format!(
+                "false_alarm_probability must be between 0 and 1, got {pfa}"
+            )));
+        }
+        if self.antenna.sidelobe_level_db >= 0.0 {
+            return Err(ConfigError::Invalid(
+                "sidelobe_level must be below 0 dB".to_string(),
+            ));
+        }
+        if self.range_scales.is_empty() {
+            return Err(ConfigError::Invalid(
+                "range_scales must not be empty".to_string(),
+            ));
+        }
+        if self
+            .spawn_mix
+            .weights()
+            .iter()
+            .any(|(_, weight)| !(*weight >= 0.0 && weight.is_finite()))
+        {
+            return Err(ConfigError::Invalid(
+                "spawn_mix weights must be zero or positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// A radar widget set up with these settings and seed.
+    pub fn build_radar(&self) -> RadarWidget {
+        let mut radar = RadarWidget::new(self.max_range, self.fade_duration);
+        if let Some(seed) = self.seed {
+            radar.reseed(seed);
+        }
+        radar.rotation_rate = self.rotation_rate;
+        radar.antenna = self.antenna.clone();
+        radar.detection = self.detection.clone();
+        radar.measurement = self.measurement.clone();
+        radar.tracker.process_noise = self.process_noise;
+        radar.tracker.gate = self.gate;
+        radar.tracker.association = self.association;
+        radar.tracker.confirm_hits = self.confirm_hits;
+        radar.tracker.confirm_looks = self.confirm_looks;
+        radar.tracker.drop_misses = self.drop_misses;
+        radar.tracker.history_length = self.trail_length;
+        radar.leader_minutes = self.leader_minutes;
+        radar.cpa_limit = self.cpa_limit;
+        radar.tcpa_limit = self.tcpa_limit;
+        radar.zones = self.zones.clone();
+        radar.set_own_ship(self.own_ship.clone());
+        radar.set_orientation(self.orientation);
+        radar.set_true_motion(self.true_motion);
+        radar.bearing_convention = self.bearing_convention;
+        radar.range_rings = self.range_rings;
+        radar.bearing_spacing = self.bearing_spacing;
+        radar.cell_aspect = self.cell_aspect;
+        radar.set_range_scales(self.range_scales.clone());
+        radar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_later_layers_win() {
+        let file: Settings = toml::from_str("tick_rate = 20.0\nmax_range = 500.0").unwrap();
+        let cli =
+            Cli::try_parse_from(["tui_radar_sim", "--tick-rate", "30", "--seed", "7"]).unwrap();
+
+        let config = Config::resolve(file.merge(cli.settings)).unwrap();
+
+        assert_eq!(config.tick_rate, 30.0);
+        assert_eq!(config.max_range, 500.0);
+        assert_eq!(config.seed, Some(7));
+        assert_eq!(config.range_scales, vec![125.0, 250.0, 500.0]);
+    }
+
+    #[test]
+    fn test_zones_from_config_file() {
+        let file: Settings = toml::from_str(
+            r#"
+            [[zones]]
+            name = "Approach"
+            types = ["aircraft"]
+            sector = { min_range = 150.0, max_range = 400.0, start_bearing = 30.0, end_bearing = 60.0 }
+
+            [[zones]]
+            name = "Bad"
+            ring = -1.0
+            "#,
+        )
+        .unwrap();
+        let err = Config::resolve(file.clone()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration: zones[1] (Bad): ring must be a positive radius"
+        );
+
+        let zones = file.zones.unwrap()[..1].to_vec();
+        let config = Config::resolve(Settings {
+            zones: Some(zones),
+            ..Settings::default()
+        })
+        .unwrap();
+        assert_eq!(config.zones.len(), 1);
+        assert!(config.zones[0].watches(ObjectType::AirCraft));
+        assert!(!config.zones[0].watches(ObjectType::Ship));
+    }
+
+    #[test]
+    fn test_spawn_mix_parse() {
+        let mix: SpawnMix = "aircraft=1, weather=0".parse().unwrap();
+        assert_eq!(mix.aircraft, 1.0);
+        assert_eq!(mix.weather, 0.0);
+        assert_eq!(mix.ship, SpawnMix::default().ship);
+
+        assert!("plane=3".parse::<SpawnMix>().is_err());
+    }
+
+    #[test]
+    fn test_default_fade_follows_revolution_period() {
+        let config = Config::resolve(Settings {
+            rotation_rate: Some(12.0),
+            ..Settings::default()
+        })
+        .unwrap();
+        assert_eq!(config.fade_duration, 56.0);
+        assert_eq!(
+            Config::resolve(Settings::default()).unwrap().fade_duration,
+            RadarWidget::FADE_DURATION
+        );
+    }
+
+    #[test]
+    fn test_full_turn_per_tick_rejected() {
+        let settings = Settings {
+            rotation_rate: Some(800.0),
+            tick_rate: Some(1.0),
+            ..Settings::default()
+        };
+        assert!(matches!(
+            Config::resolve(settings),
+            Err(ConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_tick_rate_rejected() {
+        let settings = Settings {
+            tick_rate: Some(0.0),
+            ..Settings::default()
+        };
+        assert!(matches!(
+            Config::resolve(settings),
+            Err(ConfigError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+
+/// Block characters from lowest to highest, for text sparklines.
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Contact count and new-contact rate sampled at a fixed simulation-time
+/// interval, oldest first, for the Contacts panel sparklines.
+#[derive(Debug, Clone)]
+pub struct ContactHistory {
+    /// Simulation seconds between samples.
+    pub interval: f64,
+    /// Samples kept of each series.
+    pub capacity: usize,
+    next_sample: f64,
+    new_since_sample: u64,
+    pub counts: VecDeque<u64>,
+    /// Contacts confirmed during each interval.
+    pub new_contacts: VecDeque<u64>,
+}
+
+impl ContactHistory {
+    pub fn new(interval: f64, capacity: usize) -> Self {
+        Self {
+            interval,
+            capacity,
+            next_sample: interval,
+            new_since_sample: 0,
+            counts: VecDeque::with_capacity(capacity),
+            new_contacts: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Note a contact confirmed since the last sample.
+    pub fn record_new(&mut self) {
+        self.new_since_sample += 1;
+    }
+
+    /// Take a sample of `count` contacts if an interval has passed by `now`.
+    pub fn sample(&mut self, now: f64, count: usize) {
+        if now < self.next_sample {
+            return;
+        }
+        if self.counts.len() == self.capacity {
+            self.counts.pop_front();
+            self.new_contacts.pop_front();
+        }
+        self.counts.push_back(count as u64);
+        self.new_contacts.push_back(self.new_since_sample);
+        self.new_since_sample = 0;
+        self.next_sample += self.interval;
+        // After a long pause in calls, start again from now rather than
+        // recording a burst of identical samples
+        if self.next_sample <= now {
+            self.next_sample = now + self.interval;
+        }
+    }
+
+    /// Forget all samples, sampling again one interval after `now`.
+    pub fn clear(&mut self, now: f64) {
+        self.counts.clear();
+        self.new_contacts.clear();
+        self.new_since_sample = 0;
+        self.next_sample = now + self.interval;
+    }
+
+    /// Simulation seconds covered by a full history.
+    pub fn span(&self) -> f64 {
+        self.interval * self.capacity as f64
+    }
+}
+
+/// The last `width` of `values` as block characters scaled to the largest
+/// of them; a flat low line when all are zero.
+pub fn sparkline(values: &VecDeque<u64>, width: usize) -> String {
+    let shown = values.iter().skip(values.len().saturating_sub(width));
+    let max = shown.clone().copied().max().unwrap_or(0).max(1);
+    let top = (SPARK_LEVELS.len() - 1) as u64;
+    shown
+        .map(|&value| SPARK_LEVELS[(value * top / max) as usize])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_each_interval_and_keeps_capacity() {
+        let mut history = ContactHistory::new(5.0, 3);
+        history.record_new();
+        history.sample(4.9, 1);
+        assert!(history.counts.is_empty());
+        history.sample(5.0, 1);
+        history.record_new();
+        history.record_new();
+        for (time, count) in [(10.0, 3), (15.0, 2), (20.0, 4)] {
+            history.sample(time, count);
+        }
+        assert_eq!(history.counts, [3, 2, 4]);
+        assert_eq!(history.new_contacts, [2, 0, 0]);
+    }
+
+    #[test]
+    fn test_sparkline_scales_to_largest() {
+        let values = VecDeque::from([9, 0, 7, 14]);
+        assert_eq!(sparkline(&values, 3), "▁▄█");
+        assert_eq!(sparkline(&VecDeque::from([0, 0]), 5), "▁▁");
+    }
+}
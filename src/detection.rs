@@ -0,0 +1,236 @@
+use crate::antenna::db_to_linear;
+use clap::ValueEnum;
+use rand::Rng;
+use serde::Deserialize;
+
+/// Swerling target fluctuation models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+pub enum Swerling {
+    /// Non-fluctuating (Swerling 0/V).
+    #[serde(rename = "0")]
+    #[value(name = "0")]
+    Steady,
+    /// Many equal scatterers, RCS changes scan to scan.
+    #[serde(rename = "1")]
+    #[value(name = "1")]
+    I,
+    /// Many equal scatterers, RCS changes pulse to pulse.
+    #[serde(rename = "2")]
+    #[value(name = "2")]
+    II,
+    /// One dominant scatterer, RCS changes scan to scan.
+    #[serde(rename = "3")]
+    #[value(name = "3")]
+    III,
+    /// One dominant scatterer, RCS changes pulse to pulse.
+    #[serde(rename = "4")]
+    #[value(name = "4")]
+    IV,
+}
+
+impl Swerling {
+    /// Whether a new RCS sample is drawn for every look rather than once per scan.
+    pub fn pulse_to_pulse(&self) -> bool {
+        matches!(self, Swerling::II | Swerling::IV)
+    }
+
+    /// Draw an RCS multiplier with mean 1.0.
+    pub fn sample(&self, rng: &mut impl Rng) -> f64 {
+        match self {
+            Swerling::Steady => 1.0,
+            // Chi-square with 2 degrees of freedom
+            Swerling::I | Swerling::II => exponential(rng),
+            // Chi-square with 4 degrees of freedom
+            Swerling::III | Swerling::IV => (exponential(rng) + exponential(rng)) / 2.0,
+        }
+    }
+}
+
+/// Radar power and noise budget, reduced to the SNR of a reference target.
+///
+/// SNR falls with range to the fourth power:
+/// `snr = reference_snr * (rcs / 1 m²) * (reference_range / range)⁴ * antenna_gain`,
+/// flattened inside the STC range so close targets do not swamp the display.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectionModel {
+    /// SNR in dB of a 1 m² target at `reference_range` on boresight.
+    pub reference_snr_db: f64,
+    pub reference_range: f64,
+    /// Chance that noise alone crosses the threshold on a single look.
+    pub false_alarm_probability: f64,
+    /// Sensitivity time control: inside this range the gain is cut so SNR stops rising.
+    pub stc_range: f64,
+    /// Overrides the per-type fluctuation model when set.
+    pub swerling: Option<Swerling>,
+}
+
+impl Default for DetectionModel {
+    fn default() -> Self {
+        Self {
+            reference_snr_db: 8.0,
+            reference_range: 1000.0,
+            false_alarm_probability: 1e-6,
+            stc_range: 250.0,
+            swerling: None,
+        }
+    }
+}
+
+impl DetectionModel {
+    /// Mean SNR (linear) for a target of `rcs` m² at `range` seen with `gain`.
+    pub fn snr(&self, rcs: f64, range: f64, gain: f64) -> f64 {
+        let range = range.max(self.stc_range).max(self.reference_range * 1e-3);
+        db_to_linear(self.reference_snr_db) * rcs * (self.reference_range / range).powi(4) * gain
+    }
+
+    /// Detection threshold in noise power units for a square-law detector.
+    pub fn threshold(&self) -> f64 {
+        -self.false_alarm_probability.ln()
+    }
+
+    /// Received power, in noise units, of a signal with `snr` plus complex Gaussian noise.
+    pub fn sample_power(&self, rng: &mut impl Rng, snr: f64) -> f64 {
+        let (noise_i, noise_q) = complex_noise(rng);
+        (snr.sqrt() + noise_i).powi(2) + noise_q.powi(2)
+    }
+}
+
+/// Measurement errors and resolution of the plot extractor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeasurementModel {
+    /// Standard deviation of range error, in range units.
+    pub range_noise: f64,
+    /// Standard deviation of bearing error, in degrees.
+    pub bearing_noise: f64,
+    /// Width of a range bin, in range units.
+    pub range_bin: f64,
+    /// Width of an azimuth cell, in degrees.
+    pub azimuth_cell: f64,
+}
+
+impl Default for MeasurementModel {
+    fn default() -> Self {
+        Self {
+            range_noise: 2.0,
+            bearing_noise: 0.3,
+            range_bin: 5.0,
+            azimuth_cell: 1.0,
+        }
+    }
+}
+
+impl MeasurementModel {
+    /// Add noise to a true bearing and range, then snap both to their cell centers.
+    pub fn measure(&self, rng: &mut impl Rng, angle: f64, distance: f64) -> (f64, f64) {
+        let angle = angle + self.bearing_noise * gaussian(rng);
+        let distance = (distance + self.range_noise * gaussian(rng)).max(0.0);
+        (self.quantize_angle(angle), self.quantize_range(distance))
+    }
+
+    /// Cartesian `[[xx, xy], [yx, yy]]` error covariance of a plot at `angle` and `distance`,
+    /// counting quantization as uniform error across the cell.
+    pub fn covariance(&self, angle: f64, distance: f64) -> [[f64; 2]; 2] {
+        let range_variance = self.range_noise.powi(2) + self.range_bin.powi(2) / 12.0;
+        let bearing_error = (self.bearing_noise.powi(2) + self.azimuth_cell.powi(2) / 12.0)
+            .sqrt()
+            .to_radians();
+        let cross_variance = (distance * bearing_error).powi(2);
+        let (sin, cos) = angle.to_radians().sin_cos();
+        [
+            [
+                range_variance * cos * cos + cross_variance * sin * sin,
+                (range_variance - cross_variance) * sin * cos,
+            ],
+            [
+                (range_variance - cross_variance) * sin * cos,
+                range_variance * sin * sin + cross_variance * cos * cos,
+            ],
+        ]
+    }
+
+    pub fn range_bin_index(&self, distance: f64) -> i64 {
+        (distance / self.range_bin).floor() as i64
+    }
+
+    pub fn azimuth_cell_index(&self, angle: f64) -> i64 {
+        (angle.rem_euclid(360.0) / self.azimuth_cell).floor() as i64
+    }
+
+    pub fn quantize_range(&self, distance: f64) -> f64 {
+        (self.range_bin_index(distance) as f64 + 0.5) * self.range_bin
+    }
+
+    pub fn quantize_angle(&self, angle: f64) -> f64 {
+        (self.azimuth_cell_index(angle) as f64 + 0.5) * self.azimuth_cell
+    }
+}
+
+/// A standard normal sample.
+pub fn gaussian(rng: &mut impl Rng) -> f64 {
+    complex_noise(rng).0 * std::f64::consts::SQRT_2
+}
+
+fn exponential(rng: &mut impl Rng) -> f64 {
+    -(1.0 - rng.random::<f64>()).ln()
+}
+
+/// Two independent normal samples with variance 1/2 each, so total noise power is 1.
+fn complex_noise(rng: &mut impl Rng) -> (f64, f64) {
+    // Box-Muller
+    let radius = exponential(rng).sqrt();
+    let theta = rng.random::<f64>() * std::f64::consts::TAU;
+    (radius * theta.cos(), radius * theta.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Analytic single-look probability of detection for a Swerling I target.
+    fn swerling_one_pd(false_alarm_probability: f64, snr: f64) -> f64 {
+        false_alarm_probability.powf(1.0 / (1.0 + snr))
+    }
+
+    #[test]
+    fn test_snr_falls_with_fourth_power() {
+        let model = DetectionModel::default();
+        let near = model.snr(1.0, 500.0, 1.0);
+        let far = model.snr(1.0, 1000.0, 1.0);
+        assert!((near / far - 16.0).abs() < 1e-9);
+        assert!((far - db_to_linear(model.reference_snr_db)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_noiseless_measurement_snaps_to_cell_center() {
+        let model = MeasurementModel {
+            range_noise: 0.0,
+            bearing_noise: 0.0,
+            ..MeasurementModel::default()
+        };
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(model.measure(&mut rng, 45.2, 101.0), (45.5, 102.5));
+        assert_eq!(model.measure(&mut rng, 359.9, 4.0), (359.5, 2.5));
+    }
+
+    #[test]
+    fn test_monte_carlo_matches_swerling_one() {
+        let model = DetectionModel {
+            false_alarm_probability: 1e-3,
+            ..DetectionModel::default()
+        };
+        let mut rng = StdRng::seed_from_u64(3);
+        let snr = 10.0;
+        let trials = 20_000;
+        let detections = (0..trials)
+            .filter(|_| {
+                let fluctuation = Swerling::I.sample(&mut rng);
+                model.sample_power(&mut rng, snr * fluctuation) > model.threshold()
+            })
+            .count();
+
+        let measured = detections as f64 / trials as f64;
+        assert!((measured - swerling_one_pd(model.false_alarm_probability, snr)).abs() < 0.02);
+    }
+}
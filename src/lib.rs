@@ -1,4 +1,13 @@
+pub mod antenna;
+pub mod config;
+pub mod contact_history;
+pub mod detection;
 pub mod fps_counter;
+pub mod own_ship;
 pub mod radar;
+pub mod route;
+pub mod scenario;
 pub mod threadpool;
+pub mod tracker;
 pub mod tui;
+pub mod zone;
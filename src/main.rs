@@ -1,7 +1,29 @@
+use clap::Parser;
+use tui_radar_sim_core::config::{Cli, Config, Settings};
+use tui_radar_sim_core::scenario::Scenario;
 use tui_radar_sim_core::tui::{MyResult, Tui};
 
-fn main() -> MyResult<()> {
-    let mut tui = Tui::new(30.0, 15.0)?;
+fn main() {
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> MyResult<()> {
+    // defaults < config file < scenario radar section < command line
+    let file = Settings::load(cli.config.as_deref())?;
+    let scenario_path = cli.settings.scenario.clone().or(file.scenario.clone());
+    let scenario = scenario_path
+        .map(|path| Scenario::load(&path).map_err(|e| format!("{}: {e}", path.display())))
+        .transpose()?;
+    let scenario_settings = scenario
+        .as_ref()
+        .map(|s| Settings::from(&s.radar))
+        .unwrap_or_default();
+    let config = Config::resolve(file.merge(scenario_settings).merge(cli.settings))?;
+
+    let mut tui = Tui::new(config, scenario)?;
     tui.run()?;
     Ok(())
 }
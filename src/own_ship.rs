@@ -0,0 +1,109 @@
+use crate::radar::{advance_motion, normalize_angle};
+use clap::ValueEnum;
+use serde::Deserialize;
+
+/// The platform carrying the radar.
+///
+/// Positions and headings follow the simulation conventions described at
+/// [`BearingConvention`](crate::radar::BearingConvention).
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnShip {
+    pub x: f64,
+    pub y: f64,
+    pub heading: f64,
+    /// Speed in range units per second.
+    pub speed: f64,
+    /// Degrees per second, 0.0 for a steady course.
+    pub turn_rate: f64,
+}
+
+impl Default for OwnShip {
+    /// Stopped at the origin, heading north.
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            heading: 90.0,
+            speed: 0.0,
+            turn_rate: 0.0,
+        }
+    }
+}
+
+impl OwnShip {
+    pub fn with_motion(mut self, heading: f64, speed: f64) -> Self {
+        self.heading = normalize_angle(heading);
+        self.speed = speed;
+        self
+    }
+
+    pub fn with_turn_rate(mut self, turn_rate: f64) -> Self {
+        self.turn_rate = turn_rate;
+        self
+    }
+
+    pub fn position(&self) -> (f64, f64) {
+        (self.x, self.y)
+    }
+
+    /// Velocity as `(vx, vy)` in range units per second.
+    pub fn velocity(&self) -> (f64, f64) {
+        let rad = self.heading.to_radians();
+        (self.speed * rad.cos(), self.speed * rad.sin())
+    }
+
+    pub fn advance(&mut self, delta_time: f64) {
+        (self.x, self.y, self.heading) = advance_motion(
+            (self.x, self.y, self.heading),
+            self.speed,
+            self.turn_rate,
+            delta_time,
+        );
+    }
+}
+
+/// Which direction is at the top of the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Orientation {
+    /// North up; the picture does not turn with the ship.
+    #[default]
+    NorthUp,
+    /// Own ship's heading up; the picture turns whenever the ship does.
+    HeadUp,
+    /// The course held when the mode was selected up, until it is selected again.
+    CourseUp,
+}
+
+impl Orientation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Orientation::NorthUp => "North up",
+            Orientation::HeadUp => "Head up",
+            Orientation::CourseUp => "Course up",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Orientation::NorthUp => Orientation::HeadUp,
+            Orientation::HeadUp => Orientation::CourseUp,
+            Orientation::CourseUp => Orientation::NorthUp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_own_ship_turns_full_circle() {
+        let mut ship = OwnShip::default().with_motion(0.0, 2.0).with_turn_rate(6.0);
+        for _ in 0..600 {
+            ship.advance(0.1);
+        }
+        assert!(ship.x.abs() < 1e-6 && ship.y.abs() < 1e-6);
+        assert!((ship.heading - 0.0).abs() < 1e-6 || (ship.heading - 360.0).abs() < 1e-6);
+    }
+}
@@ -1,36 +1,192 @@
-use rand::Rng;
+use crate::antenna::{AntennaPattern, linear_to_db};
+use crate::detection::{DetectionModel, MeasurementModel, Swerling};
+use crate::own_ship::{Orientation, OwnShip};
+use crate::route::{Route, Steering};
+use crate::tracker::{Approach, Track, TrackEvent, TrackStatus, Tracker};
+use crate::zone::{Zone, ZoneKind};
+use clap::ValueEnum;
+use rand::distr::weighted::WeightedIndex;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use ratatui::buffer::Buffer;
-use ratatui::style::Modifier;
-use ratatui::widgets::Widget;
+use ratatui::style::{Modifier, Style};
 use ratatui::widgets::canvas::Line;
+use ratatui::widgets::{Block, Clear, Widget};
 use ratatui::{
-    layout::Rect,
+    layout::{Position, Rect},
     style::Color,
     text,
-    widgets::canvas::{Canvas, Circle},
+    widgets::canvas::{Canvas, Circle, Points},
 };
-use std::time::Instant;
+use serde::Deserialize;
+use std::collections::{BTreeSet, HashSet};
 
+/// A measured detection from one scan: noisy, quantized and possibly merged
+/// with other objects sharing its resolution cell.
 #[derive(Debug, Clone)]
-pub struct Contact {
-    pub id: u32,
+pub struct Plot {
+    /// Radar position when the plot was measured; `angle` and `distance` are from here.
+    pub origin: (f64, f64),
     pub angle: f64,
     pub distance: f64,
-    pub last_hit_time: Instant,
-    pub visibility: f64,
+    /// Received power in noise units.
+    pub amplitude: f64,
+    pub time: f64,
+    /// Classification of the strongest echo in the cell.
     pub object_type: ObjectType,
 }
 
+impl Plot {
+    /// Measured position in the world frame.
+    pub fn position(&self) -> (f64, f64) {
+        let (x, y) = polar_to_xy(self.angle, self.distance);
+        (self.origin.0 + x, self.origin.1 + y)
+    }
+}
+
+#[cfg(test)]
+impl Plot {
+    /// A unit-amplitude ship plot measured from the world origin.
+    pub(crate) fn at(angle: f64, distance: f64, time: f64) -> Self {
+        Self {
+            origin: (0.0, 0.0),
+            angle,
+            distance,
+            amplitude: 1.0,
+            time,
+            object_type: ObjectType::Ship,
+        }
+    }
+}
+
+/// One beam position's echo from an object, placed where the beam pointed rather
+/// than where the object is, so a target smears across the beam width.
 #[derive(Debug, Clone)]
-pub struct WorldObjects {
-    pub id: u32,
+pub struct Return {
+    /// Radar position when the return was painted.
+    pub origin: (f64, f64),
     pub angle: f64,
     pub distance: f64,
+    /// Received power in noise units.
+    pub amplitude: f64,
+    /// Simulation time the return was painted, in seconds.
+    pub time: f64,
     pub object_type: ObjectType,
-    pub velocity: (f64, f64),
 }
 
+impl Return {
+    /// Where the echo appears in the world frame.
+    pub fn position(&self) -> (f64, f64) {
+        let (x, y) = polar_to_xy(self.angle, self.distance);
+        (self.origin.0 + x, self.origin.1 + y)
+    }
+}
+
+/// A simulated object moving in a straight line or a constant-rate turn.
+///
+/// Positions and headings follow the simulation conventions described at
+/// [`BearingConvention`].
 #[derive(Debug, Clone)]
+pub struct WorldObjects {
+    pub id: u32,
+    pub x: f64,
+    pub y: f64,
+    pub heading: f64,
+    /// Ground speed in range units per second.
+    pub speed: f64,
+    /// Degrees per second, 0.0 for straight-line motion.
+    pub turn_rate: f64,
+    pub object_type: ObjectType,
+    /// Scripted path; while set it overrides heading, speed and turn rate.
+    pub route: Option<Route>,
+    /// Mean radar cross section in m².
+    pub rcs: f64,
+    pub swerling: Swerling,
+    /// Current RCS multiplier drawn from the fluctuation model.
+    pub fluctuation: f64,
+}
+
+/// Things that happened during a simulation step, drained by the UI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimEvent {
+    RouteFinished {
+        id: u32,
+    },
+    Track(TrackEvent),
+    /// A track's closest approach has just broken the alert limits.
+    CollisionAlert {
+        id: u32,
+        approach: Approach,
+    },
+    ZoneEntered {
+        zone: String,
+        id: u32,
+    },
+    ZoneExited {
+        zone: String,
+        id: u32,
+    },
+}
+
+impl std::fmt::Display for SimEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimEvent::RouteFinished { id } => write!(f, "#{id} finished its route"),
+            SimEvent::Track(event) => write!(f, "{event}"),
+            SimEvent::CollisionAlert { id, approach } => write!(f, "ALERT T{id}: {approach}"),
+            SimEvent::ZoneEntered { zone, id } => write!(f, "T{id} entered {zone}"),
+            SimEvent::ZoneExited { zone, id } => write!(f, "T{id} left {zone}"),
+        }
+    }
+}
+
+/// How bearings and headings are shown to the operator.
+///
+/// Everything inside the simulation uses math angles, in degrees with 0 = east
+/// and counter-clockwise, and positions in range units in the world frame,
+/// whose origin is where the radar starts. This only changes what is drawn
+/// and printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum BearingConvention {
+    /// 0° = east, counter-clockwise.
+    Math,
+    /// 0° = north, clockwise, as read on a compass.
+    #[default]
+    Nautical,
+}
+
+impl BearingConvention {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BearingConvention::Math => "Math",
+            BearingConvention::Nautical => "Nautical",
+        }
+    }
+
+    /// A math angle as a bearing in this convention, in `0.0..360.0`.
+    pub fn from_math(&self, angle: f64) -> f64 {
+        match self {
+            BearingConvention::Math => normalize_angle(angle),
+            BearingConvention::Nautical => normalize_angle(90.0 - angle),
+        }
+    }
+
+    /// A bearing in this convention as a math angle, in `0.0..360.0`.
+    pub fn to_math(&self, bearing: f64) -> f64 {
+        // Turning the axes round is its own inverse
+        self.from_math(bearing)
+    }
+
+    /// A math angle as a three-digit bearing, e.g. `045°`.
+    pub fn format(&self, angle: f64) -> String {
+        let degrees = self.from_math(angle).round() as u32 % 360;
+        format!("{degrees:03}°")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ObjectType {
     AirCraft,
     Ship,
@@ -42,141 +198,805 @@ pub enum ObjectType {
 
 pub struct RadarWidget {
     pub sweep_angle: f64,
-    pub detected_contacts: Vec<Contact>,
+    pub tracker: Tracker,
     pub world_objects: Vec<WorldObjects>,
     pub max_range: f64,
+    pub coverage_range: f64,
+    pub range_scales: Vec<f64>,
+    range_index: usize,
+    pub hidden_types: HashSet<ObjectType>,
+    /// The radar platform.
+    pub own_ship: OwnShip,
+    /// Where `clear` puts the own ship back.
+    own_ship_start: OwnShip,
+    orientation: Orientation,
+    /// Course held at the top of the display in course-up.
+    course_reference: f64,
+    /// Hold the picture still and move own ship across it, instead of keeping
+    /// own ship in the middle.
+    true_motion: bool,
+    /// World point shown in the middle of the display.
     center_x: f64,
     center_y: f64,
     pub fade_duration: f64,
+    /// Antenna rotation in degrees per second.
+    pub rotation_rate: f64,
+    pub antenna: AntennaPattern,
+    pub detection: DetectionModel,
+    pub measurement: MeasurementModel,
+    pub returns: Vec<Return>,
+    /// Draw a line from each track to where it will be in `leader_minutes`.
+    pub show_leaders: bool,
+    pub leader_minutes: f64,
+    /// Draw each track's recent plot positions.
+    pub show_trails: bool,
+    /// Tracks predicted to pass closer than this, in range units, raise an alert...
+    pub cpa_limit: f64,
+    /// ...if they get there within this many seconds.
+    pub tcpa_limit: f64,
+    pub zones: Vec<Zone>,
+    /// How bearings are labelled and read out.
+    pub bearing_convention: BearingConvention,
+    pub range_rings: u32,
+    /// Degrees between bearing lines.
+    pub bearing_spacing: f64,
+    /// Height of a terminal cell over its width, used to keep the scope circular.
+    pub cell_aspect: f64,
+    /// Magnification of the display range; 2.0 shows half of `max_range` each way.
+    zoom: f64,
+    /// Canvas point, in range units from the display center, shown in the middle of the view.
+    view_offset: (f64, f64),
+    /// Track highlighted on the display and pinned.
+    selected: Option<u32>,
+    /// Plots waiting for the beam to leave their azimuth cell before merging.
+    pending_plots: Vec<Plot>,
+    seed: u64,
+    rng: StdRng,
+    sim_time: f64,
+    events: Vec<SimEvent>,
 }
 
 impl RadarWidget {
     pub const DEGREES_PER_SECOND: f64 = 48.0;
+    pub const BEAM_WIDTH: f64 = 3.0;
+    /// Degrees between beam positions sampled for returns.
+    pub const AZIMUTH_STEP: f64 = 0.5;
+    /// Returns this far above the threshold are drawn at full brightness.
+    pub const VIDEO_DYNAMIC_RANGE_DB: f64 = 30.0;
+    /// Tracks never fade below this until they are dropped.
+    pub const MIN_TRACK_VISIBILITY: f64 = 0.25;
+    /// Factor by which a track must clear the CPA/TCPA limits to leave alert.
+    pub const ALERT_HYSTERESIS: f64 = 1.5;
+    /// Trail dots never fade below this.
+    pub const MIN_TRAIL_LEVEL: f64 = 0.2;
+    /// In true motion, own ship is reset once it is this fraction of the display range off center...
+    pub const TRUE_MOTION_LIMIT: f64 = 0.6;
+    /// ...to this fraction behind the center, so it crosses the display again.
+    pub const TRUE_MOTION_RESET: f64 = 0.5;
+    /// Typical terminal cell height over width.
+    pub const CELL_ASPECT: f64 = 2.0;
+    pub const MIN_ZOOM: f64 = 1.0;
+    pub const MAX_ZOOM: f64 = 64.0;
+    /// Fraction of the view range moved by one pan step.
+    pub const PAN_STEP: f64 = 0.1;
+    /// Width of the overview inset as a fraction of the scope's width.
+    pub const INSET_FRACTION: f64 = 0.25;
+    /// A click picks the nearest track within this many cell widths.
+    pub const PICK_CELLS: f64 = 2.0;
+    /// Display angle, in degrees, along which range rings are labelled.
+    pub const RANGE_LABEL_ANGLE: f64 = 80.0;
+    /// Bearing labels sit at this fraction of the display range.
+    pub const BEARING_LABEL_RADIUS: f64 = 0.93;
+    /// Seconds for a contact to fade at `DEGREES_PER_SECOND`.
+    pub const FADE_DURATION: f64 = 14.0;
+
+    /// `FADE_DURATION` scaled with the revolution period, so contacts last just
+    /// under two turns of an antenna turning at `rotation_rate`.
+    pub fn default_fade_duration(rotation_rate: f64) -> f64 {
+        RadarWidget::FADE_DURATION * RadarWidget::DEGREES_PER_SECOND / rotation_rate
+    }
+
+    /// Display ranges R steps through when none are configured.
+    pub fn default_range_scales(max_range: f64) -> Vec<f64> {
+        vec![max_range / 4.0, max_range / 2.0, max_range]
+    }
+
     pub fn new(max_range: f64, fade_duration: f64) -> Self {
+        let seed = rand::rng().random();
         Self {
             sweep_angle: 0.0,
-            detected_contacts: Vec::new(),
+            tracker: Tracker::default(),
             world_objects: Vec::new(),
             max_range,
+            coverage_range: max_range,
+            range_scales: RadarWidget::default_range_scales(max_range),
+            range_index: 2,
+            hidden_types: HashSet::new(),
+            own_ship: OwnShip::default(),
+            own_ship_start: OwnShip::default(),
+            orientation: Orientation::NorthUp,
+            course_reference: 0.0,
+            true_motion: false,
             center_x: 0.0,
             center_y: 0.0,
             fade_duration,
+            rotation_rate: RadarWidget::DEGREES_PER_SECOND,
+            antenna: AntennaPattern::new(RadarWidget::BEAM_WIDTH),
+            detection: DetectionModel {
+                reference_range: max_range,
+                stc_range: max_range / 4.0,
+                ..DetectionModel::default()
+            },
+            measurement: MeasurementModel::default(),
+            returns: Vec::new(),
+            show_leaders: true,
+            leader_minutes: 0.25,
+            show_trails: true,
+            cpa_limit: max_range * 0.1,
+            tcpa_limit: 120.0,
+            zones: vec![Zone::danger_close(max_range * 0.1)],
+            bearing_convention: BearingConvention::default(),
+            range_rings: 4,
+            bearing_spacing: 30.0,
+            cell_aspect: RadarWidget::CELL_ASPECT,
+            zoom: 1.0,
+            view_offset: (0.0, 0.0),
+            selected: None,
+            pending_plots: Vec::new(),
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            sim_time: 0.0,
+            events: Vec::new(),
+        }
+    }
+
+    /// Use a fixed seed so every spawn and behavior draw can be replayed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.reseed(seed);
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Seconds of simulated time elapsed, advanced only by `update_sweep`.
+    pub fn sim_time(&self) -> f64 {
+        self.sim_time
+    }
+
+    /// Take every event raised since the last call.
+    pub fn drain_events(&mut self) -> Vec<SimEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Restart the simulation RNG from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Replace the selectable display ranges, keeping the current range if it is in the list.
+    pub fn set_range_scales(&mut self, scales: Vec<f64>) {
+        if scales.is_empty() {
+            return;
+        }
+        self.range_index = scales
+            .iter()
+            .position(|&scale| scale == self.max_range)
+            .unwrap_or(scales.len() - 1);
+        self.max_range = scales[self.range_index];
+        self.range_scales = scales;
+    }
+
+    /// Step the display range to the next scale, wrapping back to the first one.
+    pub fn cycle_range(&mut self) {
+        if self.range_scales.is_empty() {
+            return;
+        }
+        self.range_index = (self.range_index + 1) % self.range_scales.len();
+        self.max_range = self.range_scales[self.range_index];
+    }
+
+    /// Place the radar platform; `clear` returns it here.
+    pub fn set_own_ship(&mut self, own_ship: OwnShip) {
+        self.own_ship_start = own_ship.clone();
+        self.own_ship = own_ship;
+        self.recenter();
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Turn the display; selecting course-up takes the current heading as the course to hold.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        if orientation == Orientation::CourseUp {
+            self.course_reference = self.own_ship.heading;
+        }
+        self.orientation = orientation;
+    }
+
+    pub fn is_true_motion(&self) -> bool {
+        self.true_motion
+    }
+
+    pub fn set_true_motion(&mut self, true_motion: bool) {
+        self.true_motion = true_motion;
+        self.recenter();
+    }
+
+    fn recenter(&mut self) {
+        (self.center_x, self.center_y) = self.own_ship.position();
+    }
+
+    /// Follow own ship in relative motion. In true motion, leave the picture
+    /// still until own ship nears the edge, then reset it to trail the center.
+    fn update_display_center(&mut self) {
+        let (x, y) = self.own_ship.position();
+        let offset = (x - self.center_x).hypot(y - self.center_y);
+        if self.true_motion && offset <= RadarWidget::TRUE_MOTION_LIMIT * self.max_range {
+            return;
+        }
+        self.recenter();
+        if self.true_motion && self.own_ship.speed > 0.0 {
+            let (vx, vy) = self.own_ship.velocity();
+            let lead = RadarWidget::TRUE_MOTION_RESET * self.max_range / self.own_ship.speed;
+            self.center_x += vx * lead;
+            self.center_y += vy * lead;
+        }
+    }
+
+    /// Degrees the picture is turned counter-clockwise to bring the orientation's
+    /// reference direction to the top.
+    pub fn display_rotation(&self) -> f64 {
+        match self.orientation {
+            Orientation::NorthUp => 0.0,
+            Orientation::HeadUp => 90.0 - self.own_ship.heading,
+            Orientation::CourseUp => 90.0 - self.course_reference,
+        }
+    }
+
+    /// Range shown each way from the middle of the view along its shorter side.
+    pub fn view_range(&self) -> f64 {
+        self.max_range / self.zoom
+    }
+
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Magnify the view by `factor`, within `MIN_ZOOM..=MAX_ZOOM`.
+    pub fn zoom_by(&mut self, factor: f64) {
+        self.zoom = (self.zoom * factor).clamp(RadarWidget::MIN_ZOOM, RadarWidget::MAX_ZOOM);
+    }
+
+    /// Move the view by `PAN_STEP` of the view range per unit of `(dx, dy)`,
+    /// in screen directions, keeping its middle within the display range.
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        let step = RadarWidget::PAN_STEP * self.view_range();
+        self.move_view_to((
+            self.view_offset.0 + dx * step,
+            self.view_offset.1 + dy * step,
+        ));
+    }
+
+    /// Magnify the view by `factor` about the cell at `(column, row)` of the
+    /// scope drawn in `area`, so the point under it stays put; about the
+    /// middle of the view when the cell is outside the scope.
+    pub fn zoom_at(&mut self, factor: f64, area: Rect, column: u16, row: u16) {
+        let Some(point) = self.screen_to_display(area, column, row) else {
+            self.zoom_by(factor);
+            return;
+        };
+        let before = self.zoom;
+        self.zoom_by(factor);
+        let shrink = before / self.zoom;
+        self.move_view_to((
+            point.0 - (point.0 - self.view_offset.0) * shrink,
+            point.1 - (point.1 - self.view_offset.1) * shrink,
+        ));
+    }
+
+    /// Put the middle of the view at `(x, y)` in canvas coordinates, kept
+    /// within the display range.
+    fn move_view_to(&mut self, (x, y): (f64, f64)) {
+        let limit = self.max_range / x.hypot(y).max(self.max_range);
+        self.view_offset = (x * limit, y * limit);
+    }
+
+    pub fn selected(&self) -> Option<u32> {
+        self.selected
+    }
+
+    pub fn selected_track(&self) -> Option<&Track> {
+        self.tracker
+            .tracks
+            .iter()
+            .find(|track| Some(track.id) == self.selected)
+    }
+
+    /// Select the track `id`, pinning it so it is neither dropped nor faded
+    /// while it coasts, and release the one selected before.
+    pub fn select(&mut self, id: Option<u32>) {
+        for track in &mut self.tracker.tracks {
+            track.pinned = Some(track.id) == id;
+        }
+        self.selected = id;
+    }
+
+    /// Move the selection `step` places through the visible confirmed and
+    /// coasting tracks, in track number order, wrapping around.
+    pub fn cycle_selection(&mut self, step: isize) {
+        let ids: Vec<u32> = self
+            .visible_tracks()
+            .filter(|track| track.status != TrackStatus::Tentative)
+            .map(|track| track.id)
+            .collect();
+        if ids.is_empty() {
+            self.select(None);
+            return;
+        }
+        let len = ids.len() as isize;
+        let index = match self
+            .selected
+            .and_then(|id| ids.iter().position(|&i| i == id))
+        {
+            Some(index) => (index as isize + step).rem_euclid(len),
+            None if step >= 0 => 0,
+            None => len - 1,
+        };
+        self.select(Some(ids[index as usize]));
+    }
+
+    /// Move the view onto the selected track, or back onto the display center
+    /// when nothing tracked is selected.
+    pub fn center_on_selected(&mut self) {
+        let target = self
+            .selected_track()
+            .map_or((0.0, 0.0), |track| self.to_display(track.position()));
+        self.move_view_to(target);
+    }
+
+    /// Back to the whole display range around the display center.
+    pub fn reset_view(&mut self) {
+        self.zoom = 1.0;
+        self.view_offset = (0.0, 0.0);
+    }
+
+    /// Whether the view shows less than the whole display range.
+    pub fn is_view_moved(&self) -> bool {
+        self.zoom != 1.0 || self.view_offset != (0.0, 0.0)
+    }
+
+    /// Canvas bounds for `area` that show the view range each way from the
+    /// middle of the view along the shorter side and scale both axes alike,
+    /// so rings stay round.
+    pub fn bounds(&self, area: Rect) -> ([f64; 2], [f64; 2]) {
+        self.fit_bounds(area, self.view_offset, self.view_range())
+    }
+
+    fn fit_bounds(&self, area: Rect, (x, y): (f64, f64), range: f64) -> ([f64; 2], [f64; 2]) {
+        let width = f64::from(area.width.max(1));
+        let height = f64::from(area.height.max(1)) * self.cell_aspect;
+        let (half_width, half_height) = if width >= height {
+            (range * width / height, range)
+        } else {
+            (range, range * height / width)
+        };
+        (
+            [x - half_width, x + half_width],
+            [y - half_height, y + half_height],
+        )
+    }
+
+    /// Columns and rows of the largest circular scope that fits in `area`; the
+    /// rest of the area is free for other panels.
+    pub fn scope_size(&self, area: Rect) -> (u16, u16) {
+        let width = f64::from(area.width).min(f64::from(area.height) * self.cell_aspect);
+        let height = (width / self.cell_aspect).round() as u16;
+        (width.round() as u16, height.min(area.height))
+    }
+
+    /// Map a world point to canvas coordinates.
+    pub fn to_display(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let (dx, dy) = (x - self.center_x, y - self.center_y);
+        let rad = self.display_rotation().to_radians();
+        (
+            dx * rad.cos() - dy * rad.sin(),
+            dx * rad.sin() + dy * rad.cos(),
+        )
+    }
+
+    /// Map canvas coordinates back to a world point; the inverse of `to_display`.
+    pub fn from_display(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let rad = self.display_rotation().to_radians();
+        (
+            self.center_x + x * rad.cos() + y * rad.sin(),
+            self.center_y - x * rad.sin() + y * rad.cos(),
+        )
+    }
+
+    /// Canvas coordinates of the middle of the cell at `(column, row)` when the
+    /// scope is drawn in `area`, or `None` for a cell outside it.
+    fn screen_to_display(&self, area: Rect, column: u16, row: u16) -> Option<(f64, f64)> {
+        if !area.contains(Position::new(column, row)) {
+            return None;
+        }
+        let ([left, right], [bottom, top]) = self.bounds(area);
+        let across = (f64::from(column - area.x) + 0.5) / f64::from(area.width);
+        let down = (f64::from(row - area.y) + 0.5) / f64::from(area.height);
+        Some((left + across * (right - left), top - down * (top - bottom)))
+    }
+
+    /// World point under the cell at `(column, row)` when the scope is drawn in
+    /// `area`, or `None` for a cell outside it.
+    pub fn screen_to_world(&self, area: Rect, column: u16, row: u16) -> Option<(f64, f64)> {
+        self.screen_to_display(area, column, row)
+            .map(|point| self.from_display(point))
+    }
+
+    /// The confirmed or coasting track drawn nearest the cell at
+    /// `(column, row)`, if one is within `PICK_CELLS` cell widths of it.
+    pub fn track_at(&self, area: Rect, column: u16, row: u16) -> Option<u32> {
+        let (x, y) = self.screen_to_world(area, column, row)?;
+        let ([left, right], _) = self.bounds(area);
+        let reach = RadarWidget::PICK_CELLS * (right - left) / f64::from(area.width);
+        self.visible_tracks()
+            .filter(|track| track.status != TrackStatus::Tentative)
+            .map(|track| (track.id, track.distance_from((x, y))))
+            .filter(|&(_, distance)| distance <= reach)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(id, _)| id)
+    }
+
+    pub fn is_type_visible(&self, object_type: ObjectType) -> bool {
+        !self.hidden_types.contains(&object_type)
+    }
+
+    pub fn toggle_type_filter(&mut self, object_type: ObjectType) {
+        if !self.hidden_types.remove(&object_type) {
+            self.hidden_types.insert(object_type);
+        }
+    }
+
+    /// Tracks that pass the type filter, used for both rendering and counts.
+    pub fn visible_tracks(&self) -> impl Iterator<Item = &Track> {
+        self.tracker
+            .tracks
+            .iter()
+            .filter(|track| self.is_type_visible(track.object_type))
+    }
+
+    /// Confirmed or coasting tracks whose closest approach breaks the alert limits.
+    ///
+    /// A track already in alert keeps it until it clears the limits by a margin,
+    /// so a noisy velocity estimate does not make the alarm flicker.
+    pub fn is_alerting(&self, track: &Track) -> bool {
+        if track.status == TrackStatus::Tentative {
+            return false;
+        }
+        let margin = if track.alert {
+            RadarWidget::ALERT_HYSTERESIS
+        } else {
+            1.0
+        };
+        let approach = track.approach(&self.own_ship);
+        approach.cpa <= self.cpa_limit * margin
+            && (0.0..=self.tcpa_limit * margin).contains(&approach.tcpa)
+    }
+
+    /// Visible tracks in alert, soonest closest approach first.
+    pub fn alerts(&self) -> Vec<(&Track, Approach)> {
+        let mut alerts: Vec<_> = self
+            .visible_tracks()
+            .filter(|track| track.alert)
+            .map(|track| (track, track.approach(&self.own_ship)))
+            .collect();
+        alerts.sort_by(|a, b| a.1.tcpa.total_cmp(&b.1.tcpa));
+        alerts
+    }
+
+    /// Drop every track and world object and restart the clock and sweep, keeping settings.
+    pub fn clear(&mut self) {
+        self.tracker.clear();
+        self.world_objects.clear();
+        self.returns.clear();
+        self.pending_plots.clear();
+        self.events.clear();
+        for zone in &mut self.zones {
+            zone.inside.clear();
         }
+        self.own_ship = self.own_ship_start.clone();
+        self.recenter();
+        self.selected = None;
+        self.sweep_angle = 0.0;
+        self.sim_time = 0.0;
     }
 
     pub fn update_sweep(&mut self, delta_time: f64) {
+        self.sim_time += delta_time;
+
         let old_angle = self.sweep_angle;
-        self.sweep_angle += delta_time * RadarWidget::DEGREES_PER_SECOND;
-        if self.sweep_angle >= 360.0 {
-            self.sweep_angle -= 360.0;
-        }
+        self.sweep_angle = normalize_angle(self.sweep_angle + delta_time * self.rotation_rate);
 
         self.update_target_visibility();
 
         // Check for sweep hits
         self.check_sweep_hits(old_angle);
+
+        self.update_alerts();
+        self.update_zones();
+    }
+
+    /// Raise entry and exit events for tracks crossing guard zone boundaries.
+    fn update_zones(&mut self) {
+        let (own_x, own_y) = self.own_ship.position();
+        for zone in &mut self.zones {
+            if zone.kind != ZoneKind::Guard || !zone.enabled {
+                zone.inside.clear();
+                continue;
+            }
+            let inside: BTreeSet<u32> = self
+                .tracker
+                .tracks
+                .iter()
+                .filter(|track| {
+                    track.status != TrackStatus::Tentative && zone.watches(track.object_type)
+                })
+                .filter(|track| {
+                    let (x, y) = track.position();
+                    zone.shape.contains(x - own_x, y - own_y)
+                })
+                .map(|track| track.id)
+                .collect();
+
+            for &id in inside.difference(&zone.inside) {
+                self.events.push(SimEvent::ZoneEntered {
+                    zone: zone.name.clone(),
+                    id,
+                });
+            }
+            // Dropped tracks are lost rather than gone, so only report live ones leaving
+            for &id in zone.inside.difference(&inside) {
+                if self.tracker.tracks.iter().any(|track| track.id == id) {
+                    self.events.push(SimEvent::ZoneExited {
+                        zone: zone.name.clone(),
+                        id,
+                    });
+                }
+            }
+            zone.inside = inside;
+        }
+    }
+
+    /// Whether radar-relative `(x, y)` lies in an enabled exclusion zone.
+    pub fn is_excluded(&self, x: f64, y: f64) -> bool {
+        self.zones.iter().any(|zone| {
+            zone.enabled && zone.kind == ZoneKind::Exclusion && zone.shape.contains(x, y)
+        })
+    }
+
+    fn update_alerts(&mut self) {
+        let alerting: Vec<bool> = self
+            .tracker
+            .tracks
+            .iter()
+            .map(|track| self.is_alerting(track))
+            .collect();
+        for (track, alert) in self.tracker.tracks.iter_mut().zip(alerting) {
+            if alert && !track.alert {
+                self.events.push(SimEvent::CollisionAlert {
+                    id: track.id,
+                    approach: track.approach(&self.own_ship),
+                });
+            }
+            track.alert = alert;
+        }
     }
 
     fn update_target_visibility(&mut self) {
-        let now = Instant::now();
-
-        // Remove contacts that are too old haven't been hit in 2 full sweeps
-        let max_age = self.fade_duration * 2.0;
-        self.detected_contacts
-            .retain(|contact| now.duration_since(contact.last_hit_time).as_secs_f64() < max_age);
-
-        // Update visibility for remaining contacts
-        for target in &mut self.detected_contacts {
-            let time_since_hit = now.duration_since(target.last_hit_time).as_secs_f64();
-            if time_since_hit < self.fade_duration {
-                target.visibility = (1.0 - (time_since_hit / self.fade_duration)).max(0.0);
+        let now = self.sim_time;
+
+        self.returns
+            .retain(|ret| now - ret.time < self.fade_duration);
+
+        // Dead-reckon tracks between scans; they are only dropped by missed looks
+        self.tracker.predict(now);
+        for target in &mut self.tracker.tracks {
+            let time_since_hit = now - target.last_update_time;
+            target.visibility = if target.pinned {
+                1.0
             } else {
-                target.visibility = 0.0;
-            }
+                (1.0 - (time_since_hit / self.fade_duration)).max(RadarWidget::MIN_TRACK_VISIBILITY)
+            };
         }
     }
     fn check_sweep_hits(&mut self, old_angle: f64) {
-        let now = Instant::now();
+        let now = self.sim_time;
+        let threshold = self.detection.threshold();
+        let origin = self.own_ship.position();
 
-        for world_obj in &self.world_objects {
-            if self.sweep_crossed_target(old_angle, self.sweep_angle, world_obj.angle) {
-                if let Some(contact) = self
-                    .detected_contacts
-                    .iter_mut()
-                    .find(|c| c.id == world_obj.id)
-                {
-                    // Update existing contact with new position
-                    contact.angle = world_obj.angle;
-                    contact.distance = world_obj.distance;
-                    contact.last_hit_time = now;
-                    contact.visibility = 1.0;
-                } else {
-                    // Create new contact
-                    self.detected_contacts.push(Contact {
-                        id: world_obj.id,
-                        angle: world_obj.angle,
-                        distance: world_obj.distance,
-                        last_hit_time: now,
-                        visibility: 1.0,
-                        object_type: world_obj.object_type.clone(),
+        // Scan-to-scan fluctuation is redrawn while the beam faces away from the object
+        for world_obj in &mut self.world_objects {
+            let opposite = normalize_angle(world_obj.angle_from(origin) + 180.0);
+            if sweep_crossed_target(old_angle, self.sweep_angle, opposite) {
+                let swerling = self.detection.swerling.unwrap_or(world_obj.swerling);
+                world_obj.fluctuation = swerling.sample(&mut self.rng);
+            }
+        }
+
+        let mut sweep_end = self.sweep_angle;
+        if sweep_end < old_angle {
+            sweep_end += 360.0;
+        }
+
+        // Sample the beam on a fixed azimuth grid so returns line up between ticks
+        let step = RadarWidget::AZIMUTH_STEP;
+        let mut beam = (old_angle / step).floor() * step + step;
+        while beam <= sweep_end {
+            let beam_angle = normalize_angle(beam);
+            for world_obj in &self.world_objects {
+                let distance = world_obj.distance_from(origin);
+                let off_boresight = angle_difference(beam_angle, world_obj.angle_from(origin));
+                let gain = self.antenna.gain(off_boresight);
+                let snr = world_obj.look_snr(&self.detection, gain, distance, &mut self.rng);
+                let power = self.detection.sample_power(&mut self.rng, snr);
+                if power > threshold {
+                    self.returns.push(Return {
+                        origin,
+                        angle: beam_angle,
+                        distance: self.measurement.quantize_range(distance),
+                        amplitude: power,
+                        time: now,
+                        object_type: world_obj.object_type,
                     });
                 }
-                // print!("\x07"); Bell audio
             }
+            beam += step;
+        }
+
+        // Plot each object at most once per scan, when boresight passes over it
+        for world_obj in &self.world_objects {
+            let (bearing, range) = (
+                world_obj.angle_from(origin),
+                world_obj.distance_from(origin),
+            );
+            if !sweep_crossed_target(old_angle, self.sweep_angle, bearing) {
+                continue;
+            }
+            let snr = world_obj.look_snr(&self.detection, 1.0, range, &mut self.rng);
+            let amplitude = self.detection.sample_power(&mut self.rng, snr);
+            if amplitude <= threshold {
+                continue;
+            }
+            let (angle, distance) = self.measurement.measure(&mut self.rng, bearing, range);
+            self.pending_plots.push(Plot {
+                origin,
+                angle,
+                distance,
+                amplitude,
+                time: now,
+                object_type: world_obj.object_type,
+            });
         }
+
+        let mut plots = self.take_completed_plots();
+        plots.retain(|plot| {
+            let (x, y) = polar_to_xy(plot.angle, plot.distance);
+            !self.is_excluded(x, y)
+        });
+        self.tracker.process(&plots, &self.measurement);
+
+        // A look is over once the beam is far enough past a track that its
+        // plot, if any, has left the pending cells
+        let lag = 2.0 * self.measurement.azimuth_cell + 3.0 * self.measurement.bearing_noise;
+        let sweep_angle = self.sweep_angle;
+        let events = self.tracker.close_looks(|track| {
+            let bearing = track.angle_from(origin);
+            sweep_crossed_target(old_angle, sweep_angle, normalize_angle(bearing + lag))
+        });
+        self.events.extend(events.into_iter().map(SimEvent::Track));
     }
 
-    fn sweep_crossed_target(&self, old_angle: f64, new_angle: f64, target_angle: f64) -> bool {
-        let sweep_width = 3.0; // Degrees of sweep beam width
+    /// Merge and hand back every pending plot whose azimuth cell the beam has left.
+    ///
+    /// Objects sharing a range bin and azimuth cell become one plot, reported
+    /// with the strongest return in the cell.
+    fn take_completed_plots(&mut self) -> Vec<Plot> {
+        let current_cell = self.measurement.azimuth_cell_index(self.sweep_angle);
+        let (waiting, completed): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_plots)
+            .into_iter()
+            .partition(|plot| self.measurement.azimuth_cell_index(plot.angle) == current_cell);
+        self.pending_plots = waiting;
 
-        // Handle sweep crossing 0/360 boundary
-        if new_angle < old_angle {
-            // Sweep crossed 0 degrees
-            if target_angle >= old_angle || target_angle <= new_angle {
-                let angle_diff = if target_angle >= old_angle {
-                    new_angle + (360.0 - old_angle)
-                } else {
-                    new_angle - 0.0
-                };
-                return angle_diff <= sweep_width;
-            }
-        } else {
-            // Normal case
-            if target_angle >= old_angle && target_angle <= new_angle {
-                return (target_angle - old_angle) <= sweep_width;
+        let mut merged: Vec<Plot> = Vec::new();
+        for plot in completed {
+            let cell = (
+                self.measurement.azimuth_cell_index(plot.angle),
+                self.measurement.range_bin_index(plot.distance),
+            );
+            match merged.iter_mut().find(|other| {
+                (
+                    self.measurement.azimuth_cell_index(other.angle),
+                    self.measurement.range_bin_index(other.distance),
+                ) == cell
+            }) {
+                Some(other) if plot.amplitude > other.amplitude => *other = plot,
+                Some(_) => {}
+                None => merged.push(plot),
             }
         }
-        false
+        merged
+    }
+}
+
+/// A named color scaled down to `level` brightness, for fading symbols.
+fn faded(color: Color, level: f64) -> Color {
+    let intensity = (255.0 * level.clamp(0.0, 1.0)) as u8;
+    match color {
+        Color::Red => Color::Rgb(intensity, 0, 0),
+        Color::Green => Color::Rgb(0, intensity, 0),
+        Color::Blue => Color::Rgb(0, 0, intensity),
+        Color::Cyan => Color::Rgb(0, intensity, intensity),
+        Color::Yellow => Color::Rgb(intensity, intensity, 0),
+        Color::Magenta => Color::Rgb(intensity, 0, intensity),
+        Color::White => Color::Rgb(intensity, intensity, intensity),
+        _ => Color::Rgb(intensity, intensity, 0), //yellow
+    }
+}
+
+/// Whether a sweep from `old_angle` to `new_angle` passed over `target_angle`.
+fn sweep_crossed_target(old_angle: f64, new_angle: f64, target_angle: f64) -> bool {
+    // Handle sweep crossing 0/360 boundary
+    if new_angle < old_angle {
+        target_angle > old_angle || target_angle <= new_angle
+    } else {
+        target_angle > old_angle && target_angle <= new_angle
     }
 }
 
 impl Widget for &RadarWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let (x_bounds, y_bounds) = self.bounds(area);
         let canvas = Canvas::default()
-            .x_bounds([-self.max_range, self.max_range])
-            .y_bounds([-self.max_range, self.max_range])
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
             .paint(|ctx| {
-                // Draw range rings
-                for i in 1..=4 {
-                    let radius = (i as f64) * (self.max_range / 4.0);
+                // Rings, bearing lines and the sweep are centered on own ship,
+                // which only leaves the middle in true motion
+                let (own_x, own_y) = self.to_display(self.own_ship.position());
+                let rotation = self.display_rotation();
+
+                // Draw range rings, labelled just right of the top
+                let label_rad = RadarWidget::RANGE_LABEL_ANGLE.to_radians();
+                for i in 1..=self.range_rings {
+                    let radius = (i as f64) * (self.max_range / self.range_rings as f64);
                     ctx.draw(&Circle {
-                        x: self.center_x,
-                        y: self.center_y,
+                        x: own_x,
+                        y: own_y,
                         radius,
                         color: Color::Green,
                     });
+                    ctx.print(
+                        own_x + radius * label_rad.cos(),
+                        own_y + radius * label_rad.sin(),
+                        text::Line::from(format_range(radius)).style(Color::Green),
+                    );
                 }
 
-                // Draw bearing lines
-                for angle in (0..360).step_by(30) {
-                    let rad = (angle as f64).to_radians();
+                // Draw bearing lines at round bearings in the chosen convention
+                let mut bearing = 0.0;
+                while bearing < 360.0 {
+                    let angle = self.bearing_convention.to_math(bearing);
+                    let rad = (angle + rotation).to_radians();
 
                     let start_distance = self.max_range * 0.1;
-                    let start_x = self.center_x + start_distance * rad.cos();
-                    let start_y = self.center_y + start_distance * rad.sin();
+                    let start_x = own_x + start_distance * rad.cos();
+                    let start_y = own_y + start_distance * rad.sin();
 
-                    let end_x = self.center_x + self.max_range * rad.cos();
-                    let end_y = self.center_y + self.max_range * rad.sin();
+                    let end_x = own_x + self.max_range * rad.cos();
+                    let end_y = own_y + self.max_range * rad.sin();
 
                     ctx.draw(&Line {
                         x1: start_x,
@@ -185,183 +1005,376 @@ impl Widget for &RadarWidget {
                         y2: end_y,
                         color: Color::DarkGray,
                     });
+
+                    // Pulled in a little so labels on the right edge are not cut off
+                    let label_distance = self.max_range * RadarWidget::BEARING_LABEL_RADIUS;
+                    ctx.print(
+                        own_x + label_distance * rad.cos(),
+                        own_y + label_distance * rad.sin(),
+                        text::Line::from(self.bearing_convention.format(angle)).style(Color::Gray),
+                    );
+                    bearing += self.bearing_spacing;
                 }
 
-                // Danger close range
-                ctx.draw(&Circle {
-                    x: self.center_x,
-                    y: self.center_y,
-                    radius: self.max_range * 0.1,
-                    color: Color::Blue,
-                });
+                // Guard zones turn red while occupied
+                for zone in self.zones.iter().filter(|zone| zone.enabled) {
+                    let color = match zone.kind {
+                        ZoneKind::Guard if zone.is_occupied() => Color::Red,
+                        ZoneKind::Guard => Color::Blue,
+                        ZoneKind::Exclusion => Color::DarkGray,
+                    };
+                    // Zones go where own ship goes, but keep their true bearings
+                    let (ship_x, ship_y) = self.own_ship.position();
+                    for (x1, y1, x2, y2) in zone.shape.outline() {
+                        let (x1, y1) = self.to_display((ship_x + x1, ship_y + y1));
+                        let (x2, y2) = self.to_display((ship_x + x2, ship_y + y2));
+                        ctx.draw(&Line {
+                            x1,
+                            y1,
+                            x2,
+                            y2,
+                            color,
+                        });
+                    }
+                }
 
-                // US / center
+                // Own ship and its heading marker out to the edge
                 ctx.draw(&Circle {
-                    x: self.center_x,
-                    y: self.center_y,
+                    x: own_x,
+                    y: own_y,
                     radius: 2.0,
                     color: Color::Blue,
                 });
+                let heading_rad = (self.own_ship.heading + rotation).to_radians();
+                ctx.draw(&Line {
+                    x1: own_x,
+                    y1: own_y,
+                    x2: own_x + self.max_range * heading_rad.cos(),
+                    y2: own_y + self.max_range * heading_rad.sin(),
+                    color: Color::White,
+                });
 
                 // Draw sweep line
-                let sweep_rad = self.sweep_angle.to_radians();
-                let sweep_end_x = self.center_x + self.max_range * sweep_rad.cos();
-                let sweep_end_y = self.center_y + self.max_range * sweep_rad.sin();
+                let sweep_rad = (self.sweep_angle + rotation).to_radians();
+                let sweep_end_x = own_x + self.max_range * sweep_rad.cos();
+                let sweep_end_y = own_y + self.max_range * sweep_rad.sin();
 
                 ctx.draw(&Line {
-                    x1: self.center_x,
-                    y1: self.center_y,
+                    x1: own_x,
+                    y1: own_y,
                     x2: sweep_end_x,
                     y2: sweep_end_y,
                     color: Color::Yellow,
                 });
 
-                // drawing detected contacts
-                for contact in &self.detected_contacts {
-                    if contact.visibility > 0.0 {
-                        let symbol = contact.object_type.symbol();
-                        let color = contact.object_type.color();
-
-                        let rad = contact.angle.to_radians();
-                        let x = self.center_x + contact.distance * rad.cos();
-                        let y = self.center_y + contact.distance * rad.sin();
-
-                        let intensity = (255.0 * contact.visibility) as u8;
-                        let faded_color = match color {
-                            Color::Red => Color::Rgb(intensity, 0, 0),
-                            Color::Green => Color::Rgb(0, intensity, 0),
-                            Color::Blue => Color::Rgb(0, 0, intensity),
-                            Color::Cyan => Color::Rgb(0, intensity, intensity),
-                            Color::Yellow => Color::Rgb(intensity, intensity, 0),
-                            Color::Magenta => Color::Rgb(intensity, 0, intensity),
-                            Color::White => Color::Rgb(intensity, intensity, intensity),
-                            _ => Color::Rgb(intensity, intensity, 0), //yellow
-                        };
+                // Raw video, dimmer with age and toward the detection threshold
+                let threshold = self.detection.threshold();
+                for ret in self
+                    .returns
+                    .iter()
+                    .filter(|ret| self.is_type_visible(ret.object_type))
+                {
+                    let age = (self.sim_time - ret.time) / self.fade_duration;
+                    let strength = (linear_to_db(ret.amplitude / threshold)
+                        / RadarWidget::VIDEO_DYNAMIC_RANGE_DB)
+                        .clamp(0.0, 1.0);
+                    let level = ((1.0 - age) * (0.3 + 0.7 * strength)).clamp(0.0, 1.0);
+                    let (x, y) = self.to_display(ret.position());
+                    let line = text::Line::from("·").style(Color::Rgb(
+                        0,
+                        (180.0 * level) as u8,
+                        (60.0 * level) as u8,
+                    ));
+                    ctx.print(x, y, line);
+                }
 
-                        let line = text::Line::from(format!("{symbol}"))
-                            .style((faded_color, Modifier::BOLD));
+                // Snail trails, oldest plot dimmest
+                if self.show_trails {
+                    for track in self.visible_tracks() {
+                        let count = track.history.len() as f64;
+                        for (i, &(x, y, _)) in track.history.iter().enumerate() {
+                            let level = ((i + 1) as f64 / count).max(RadarWidget::MIN_TRAIL_LEVEL);
+                            ctx.draw(&Points {
+                                coords: &[self.to_display((x, y))],
+                                color: faded(track.object_type.color(), level * track.visibility),
+                            });
+                        }
+                    }
+                }
+
+                // Velocity leaders, only once a track has a velocity estimate
+                if self.show_leaders {
+                    for track in self
+                        .visible_tracks()
+                        .filter(|track| track.status != TrackStatus::Tentative)
+                    {
+                        let (x, y) = track.position();
+                        let (vx, vy) = track.velocity();
+                        let seconds = self.leader_minutes * 60.0;
+                        let (x1, y1) = self.to_display((x, y));
+                        let (x2, y2) = self.to_display((x + vx * seconds, y + vy * seconds));
+                        ctx.draw(&Line {
+                            x1,
+                            y1,
+                            x2,
+                            y2,
+                            color: faded(track.object_type.color(), track.visibility),
+                        });
+                    }
+                }
+
+                // Tracks at their dead-reckoned positions
+                for track in self.visible_tracks() {
+                    if track.visibility > 0.0 {
+                        let symbol = track.object_type.symbol();
+                        let color = track.object_type.color();
+
+                        let (x, y) = self.to_display(track.position());
+
+                        let faded_color = faded(color, track.visibility);
+
+                        let style: Style = match track.status {
+                            _ if track.alert => Style::new()
+                                .fg(Color::White)
+                                .bg(Color::Red)
+                                .add_modifier(Modifier::BOLD),
+                            TrackStatus::Tentative => Color::DarkGray.into(),
+                            TrackStatus::Confirmed => (faded_color, Modifier::BOLD).into(),
+                            TrackStatus::Coasting => (faded_color, Modifier::DIM).into(),
+                        };
+                        if track.alert {
+                            ctx.draw(&Circle {
+                                x,
+                                y,
+                                radius: self.view_range() * 0.03,
+                                color: Color::Red,
+                            });
+                        }
+                        if Some(track.id) == self.selected {
+                            ctx.draw(&Circle {
+                                x,
+                                y,
+                                radius: self.view_range() * 0.04,
+                                color: Color::White,
+                            });
+                        }
+                        let line = text::Line::from(format!("{symbol}")).style(style);
                         ctx.print(x, y, line);
                     }
                 }
             });
         canvas.render(area, buf);
+
+        if self.is_view_moved() {
+            self.render_overview(area, buf);
+        }
+    }
+}
+
+impl RadarWidget {
+    /// Draw the full coverage in the top-right corner with the current view outlined.
+    fn render_overview(&self, area: Rect, buf: &mut Buffer) {
+        let width = (f64::from(area.width) * RadarWidget::INSET_FRACTION) as u16;
+        let height = (f64::from(width) / self.cell_aspect).round() as u16;
+        if width < 12 || height + 2 > area.height {
+            return;
+        }
+        let inset = Rect::new(area.right() - width, area.y, width, height);
+        Clear.render(inset, buf);
+        let block = Block::bordered()
+            .title("Overview")
+            .border_style(Color::DarkGray);
+        let inner = block.inner(inset);
+        block.render(inset, buf);
+
+        let own = self.to_display(self.own_ship.position());
+        let (x_bounds, y_bounds) = self.fit_bounds(inner, own, self.coverage_range);
+        let ([left, right], [bottom, top]) = self.bounds(area);
+        Canvas::default()
+            .x_bounds(x_bounds)
+            .y_bounds(y_bounds)
+            .paint(|ctx| {
+                ctx.draw(&Circle {
+                    x: own.0,
+                    y: own.1,
+                    radius: self.coverage_range,
+                    color: Color::Green,
+                });
+                let coords: Vec<_> = self
+                    .visible_tracks()
+                    .filter(|track| track.status != TrackStatus::Tentative)
+                    .map(|track| self.to_display(track.position()))
+                    .collect();
+                ctx.draw(&Points {
+                    coords: &coords,
+                    color: Color::Gray,
+                });
+                ctx.draw(&Points {
+                    coords: &[own],
+                    color: Color::Blue,
+                });
+                for (x1, y1, x2, y2) in [
+                    (left, bottom, right, bottom),
+                    (right, bottom, right, top),
+                    (right, top, left, top),
+                    (left, top, left, bottom),
+                ] {
+                    ctx.draw(&Line {
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        color: Color::Yellow,
+                    });
+                }
+            })
+            .render(inner, buf);
     }
 }
 
 impl RadarWidget {
     pub fn update_world_objects(&mut self, delta_time: f64) {
+        self.own_ship.advance(delta_time);
+        self.update_display_center();
+
         for obj in &mut self.world_objects {
-            // Update position based on velocity
-            obj.angle += obj.velocity.0 * delta_time;
-            obj.distance += obj.velocity.1 * delta_time;
-
-            // Wrap angle around
-            if obj.angle >= 360.0 {
-                obj.angle -= 360.0;
-            } else if obj.angle < 0.0 {
-                obj.angle += 360.0;
+            if let Some(event) = obj.advance(delta_time) {
+                self.events.push(event);
             }
         }
 
         // Remove objects that moved too far away
+        let origin = self.own_ship.position();
         self.world_objects
-            .retain(|obj| obj.distance > 0.0 && obj.distance <= self.max_range);
+            .retain(|obj| obj.distance_from(origin) <= self.coverage_range);
     }
     pub fn spawn_aircraft(&mut self, id: u32) {
-        let mut rng = rand::rng();
-
-        // Spawn at edge, flying across
-        let start_angle = rng.random_range(0.0..360.0);
-        let target_angle = rng.random_range(0.0..360.0);
+        let origin = self.own_ship.position();
+        let rng = &mut self.rng;
 
-        // Calculate angular velocity to fly toward target
-        let mut angle_diff = target_angle - start_angle;
-        if angle_diff > 180.0 {
-            angle_diff -= 360.0;
-        }
-        if angle_diff < -180.0 {
-            angle_diff += 360.0;
-        }
+        // Spawn at edge, flying across toward another point on the edge
+        let edge = self.coverage_range * 0.9;
+        let start_angle: f64 = rng.random_range(0.0..360.0);
+        let target_angle: f64 = rng.random_range(0.0..360.0);
+        let (start_x, start_y) = polar_to_xy(start_angle, edge);
+        let (target_x, target_y) = polar_to_xy(target_angle, edge);
+        let heading = (target_y - start_y).atan2(target_x - start_x).to_degrees();
 
-        let angular_velocity = angle_diff / 120.0;
-        let radial_velocity = rng.random_range(-2.0..2.0);
-
-        self.world_objects.push(WorldObjects {
-            id,
-            angle: start_angle,
-            distance: self.max_range * 0.9,
-            object_type: ObjectType::AirCraft,
-            velocity: (angular_velocity, radial_velocity),
-        });
+        self.world_objects.push(
+            WorldObjects::from_polar(id, ObjectType::AirCraft, start_angle, edge)
+                .translated(origin)
+                .with_motion(heading, rng.random_range(8.0..20.0)),
+        );
     }
 
     pub fn spawn_ship(&mut self, id: u32) {
-        let mut rng = rand::rng();
+        let origin = self.own_ship.position();
+        let rng = &mut self.rng;
 
-        self.world_objects.push(WorldObjects {
-            id,
-            angle: rng.random_range(0.0..360.0),
-            distance: rng.random_range(20.0..80.0),
-            object_type: ObjectType::Ship,
-            velocity: (rng.random_range(-2.0..2.0), rng.random_range(-1.0..1.0)),
-        });
+        self.world_objects.push(
+            WorldObjects::from_polar(
+                id,
+                ObjectType::Ship,
+                rng.random_range(0.0..360.0),
+                rng.random_range(self.coverage_range * 0.02..self.coverage_range * 0.08),
+            )
+            .translated(origin)
+            .with_motion(rng.random_range(0.0..360.0), rng.random_range(0.5..2.0)),
+        );
     }
 }
 
 impl RadarWidget {
     // Add the missing spawn methods
     pub fn spawn_unknown(&mut self, id: u32) {
-        let mut rng = rand::rng();
+        let origin = self.own_ship.position();
+        let rng = &mut self.rng;
 
-        self.world_objects.push(WorldObjects {
-            id,
-            angle: rng.random_range(0.0..360.0),
-            distance: rng.random_range(30.0..self.max_range * 0.8),
-            object_type: ObjectType::Unknown,
-            velocity: (rng.random_range(-1.0..1.0), rng.random_range(-2.0..2.0)),
-        });
+        self.world_objects.push(
+            WorldObjects::from_polar(
+                id,
+                ObjectType::Unknown,
+                rng.random_range(0.0..360.0),
+                rng.random_range(self.coverage_range * 0.03..self.coverage_range * 0.8),
+            )
+            .translated(origin)
+            .with_motion(rng.random_range(0.0..360.0), rng.random_range(1.0..4.0)),
+        );
     }
 
     pub fn spawn_hostile(&mut self, id: u32) {
-        let mut rng = rand::rng();
+        let origin = self.own_ship.position();
+        let rng = &mut self.rng;
 
-        // Hostiles move faster and more aggressively
-        self.world_objects.push(WorldObjects {
-            id,
-            angle: rng.random_range(0.0..360.0),
-            distance: rng.random_range(40.0..self.max_range * 0.7),
-            object_type: ObjectType::Hostile,
-            velocity: (rng.random_range(-8.0..8.0), rng.random_range(-8.0..8.0)),
-        });
+        // Hostiles move faster and maneuver
+        self.world_objects.push(
+            WorldObjects::from_polar(
+                id,
+                ObjectType::Hostile,
+                rng.random_range(0.0..360.0),
+                rng.random_range(self.coverage_range * 0.04..self.coverage_range * 0.7),
+            )
+            .translated(origin)
+            .with_motion(rng.random_range(0.0..360.0), rng.random_range(5.0..15.0))
+            .with_turn_rate(rng.random_range(-4.0..4.0)),
+        );
     }
 
     pub fn spawn_generic(&mut self, id: u32) {
-        let mut rng = rand::rng();
+        let origin = self.own_ship.position();
+        let rng = &mut self.rng;
 
-        self.world_objects.push(WorldObjects {
-            id,
-            angle: rng.random_range(0.0..360.0),
-            distance: rng.random_range(15.0..self.max_range * 0.9),
-            object_type: ObjectType::Generic,
-            velocity: (rng.random_range(-3.0..3.0), rng.random_range(-3.0..3.0)),
-        });
+        self.world_objects.push(
+            WorldObjects::from_polar(
+                id,
+                ObjectType::Generic,
+                rng.random_range(0.0..360.0),
+                rng.random_range(self.coverage_range * 0.015..self.coverage_range * 0.9),
+            )
+            .translated(origin)
+            .with_motion(rng.random_range(0.0..360.0), rng.random_range(1.0..4.0)),
+        );
     }
 
     pub fn spawn_weather(&mut self, id: u32) {
-        let mut rng = rand::rng();
+        let origin = self.own_ship.position();
+        let rng = &mut self.rng;
 
-        // Weather moves slowly and changes size/intensity
-        self.world_objects.push(WorldObjects {
-            id,
-            angle: rng.random_range(0.0..360.0),
-            distance: rng.random_range(10.0..self.max_range * 0.6),
-            object_type: ObjectType::Weather,
-            velocity: (rng.random_range(-0.1..0.1), rng.random_range(-0.2..0.2)),
-        });
+        // Weather drifts slowly with the wind
+        self.world_objects.push(
+            WorldObjects::from_polar(
+                id,
+                ObjectType::Weather,
+                rng.random_range(0.0..360.0),
+                rng.random_range(self.coverage_range * 0.01..self.coverage_range * 0.6),
+            )
+            .translated(origin)
+            .with_motion(rng.random_range(0.0..360.0), rng.random_range(0.05..0.3)),
+        );
+    }
+
+    pub fn spawn(&mut self, id: u32, object_type: ObjectType) {
+        match object_type {
+            ObjectType::AirCraft => self.spawn_aircraft(id),
+            ObjectType::Ship => self.spawn_ship(id),
+            ObjectType::Unknown => self.spawn_unknown(id),
+            ObjectType::Hostile => self.spawn_hostile(id),
+            ObjectType::Generic => self.spawn_generic(id),
+            ObjectType::Weather => self.spawn_weather(id),
+        }
+    }
+
+    /// Spawn one object whose type is drawn from `weights`; nothing spawns if every weight is zero.
+    pub fn spawn_weighted(&mut self, id: u32, weights: &[(ObjectType, f64)]) {
+        let Ok(index) = WeightedIndex::new(weights.iter().map(|(_, weight)| *weight)) else {
+            return;
+        };
+        let object_type = weights[self.rng.sample(&index)].0;
+        self.spawn(id, object_type);
     }
 
     pub fn spawn_random_object(&mut self, id: u32) {
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
 
         match rng.random_range(0..6) {
             0 => self.spawn_aircraft(id),
@@ -375,7 +1388,203 @@ impl RadarWidget {
     }
 }
 
+impl WorldObjects {
+    /// A stationary object at `angle` degrees and `distance` from the world origin.
+    pub fn from_polar(id: u32, object_type: ObjectType, angle: f64, distance: f64) -> Self {
+        let (x, y) = polar_to_xy(angle, distance);
+        Self {
+            id,
+            x,
+            y,
+            heading: 0.0,
+            speed: 0.0,
+            turn_rate: 0.0,
+            object_type,
+            route: None,
+            rcs: object_type.rcs(),
+            swerling: object_type.swerling(),
+            fluctuation: 1.0,
+        }
+    }
+
+    /// Shift the object by `(dx, dy)`, e.g. to place it around the radar's current position.
+    pub fn translated(mut self, (dx, dy): (f64, f64)) -> Self {
+        self.x += dx;
+        self.y += dy;
+        self
+    }
+
+    pub fn with_motion(mut self, heading: f64, speed: f64) -> Self {
+        self.heading = normalize_angle(heading);
+        self.speed = speed;
+        self
+    }
+
+    pub fn with_turn_rate(mut self, turn_rate: f64) -> Self {
+        self.turn_rate = turn_rate;
+        self
+    }
+
+    pub fn with_route(mut self, route: Route) -> Self {
+        self.route = Some(route);
+        self
+    }
+
+    pub fn with_rcs(mut self, rcs: f64) -> Self {
+        self.rcs = rcs;
+        self
+    }
+
+    pub fn with_swerling(mut self, swerling: Swerling) -> Self {
+        self.swerling = swerling;
+        self
+    }
+
+    /// Mean SNR for one look at antenna `gain` from `distance`, redrawing the RCS
+    /// for pulse-to-pulse models.
+    fn look_snr(
+        &self,
+        model: &DetectionModel,
+        gain: f64,
+        distance: f64,
+        rng: &mut impl Rng,
+    ) -> f64 {
+        let swerling = model.swerling.unwrap_or(self.swerling);
+        let fluctuation = if swerling.pulse_to_pulse() {
+            swerling.sample(rng)
+        } else {
+            self.fluctuation
+        };
+        model.snr(self.rcs * fluctuation, distance, gain)
+    }
+
+    /// Bearing from `origin` in degrees, in `0.0..360.0`.
+    pub fn angle_from(&self, (x, y): (f64, f64)) -> f64 {
+        normalize_angle((self.y - y).atan2(self.x - x).to_degrees())
+    }
+
+    /// Range from `origin`.
+    pub fn distance_from(&self, (x, y): (f64, f64)) -> f64 {
+        (self.x - x).hypot(self.y - y)
+    }
+
+    /// Velocity as `(vx, vy)` in range units per second.
+    pub fn velocity(&self) -> (f64, f64) {
+        let rad = self.heading.to_radians();
+        (self.speed * rad.cos(), self.speed * rad.sin())
+    }
+
+    /// Move along the current heading, turning at `turn_rate` or as the route steers.
+    ///
+    /// Returns an event when the route has just been completed.
+    pub fn advance(&mut self, delta_time: f64) -> Option<SimEvent> {
+        let mut event = None;
+        if let Some(route) = &mut self.route {
+            match route.steer(self.x, self.y, self.heading, self.speed, delta_time) {
+                Steering::Leg { turn, speed } => {
+                    self.heading = normalize_angle(self.heading + turn);
+                    self.speed = speed;
+                    self.turn_rate = 0.0;
+                }
+                Steering::Wait => {
+                    self.speed = 0.0;
+                    self.turn_rate = 0.0;
+                }
+                Steering::Loiter { turn_rate } => self.turn_rate = turn_rate,
+                Steering::Finished => {
+                    // Carry on along the last leg
+                    self.route = None;
+                    self.turn_rate = 0.0;
+                    event = Some(SimEvent::RouteFinished { id: self.id });
+                }
+            }
+        }
+
+        self.integrate(delta_time);
+        event
+    }
+
+    fn integrate(&mut self, delta_time: f64) {
+        (self.x, self.y, self.heading) = advance_motion(
+            (self.x, self.y, self.heading),
+            self.speed,
+            self.turn_rate,
+            delta_time,
+        );
+    }
+}
+
+/// Move `(x, y, heading)` on for `delta_time` seconds at `speed`, turning at
+/// `turn_rate` degrees per second.
+pub fn advance_motion(
+    (x, y, heading): (f64, f64, f64),
+    speed: f64,
+    turn_rate: f64,
+    delta_time: f64,
+) -> (f64, f64, f64) {
+    let rad = heading.to_radians();
+    if turn_rate == 0.0 {
+        return (
+            x + speed * rad.cos() * delta_time,
+            y + speed * rad.sin() * delta_time,
+            heading,
+        );
+    }
+
+    // Follow the arc exactly so a constant turn closes into a circle
+    let turn = (turn_rate * delta_time).to_radians();
+    let radius = speed / turn_rate.to_radians();
+    (
+        x + radius * ((rad + turn).sin() - rad.sin()),
+        y - radius * ((rad + turn).cos() - rad.cos()),
+        normalize_angle(heading + turn_rate * delta_time),
+    )
+}
+
+/// Convert a bearing in degrees and a range into radar-relative `(x, y)`.
+pub fn polar_to_xy(angle: f64, distance: f64) -> (f64, f64) {
+    let rad = angle.to_radians();
+    (distance * rad.cos(), distance * rad.sin())
+}
+
+/// A range for ring labels: whole numbers without a decimal point, otherwise one decimal.
+fn format_range(range: f64) -> String {
+    format!("{}", (range * 10.0).round() / 10.0)
+}
+
+/// Signed difference `a - b` in degrees, wrapped into `-180.0..180.0`.
+pub fn angle_difference(a: f64, b: f64) -> f64 {
+    (a - b + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Wrap an angle in degrees into `0.0..360.0`.
+pub fn normalize_angle(angle: f64) -> f64 {
+    let wrapped = angle.rem_euclid(360.0);
+    // rem_euclid can round up to exactly 360.0 for tiny negative inputs
+    if wrapped >= 360.0 { 0.0 } else { wrapped }
+}
+
 impl ObjectType {
+    pub const ALL: [ObjectType; 6] = [
+        ObjectType::AirCraft,
+        ObjectType::Ship,
+        ObjectType::Unknown,
+        ObjectType::Hostile,
+        ObjectType::Generic,
+        ObjectType::Weather,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ObjectType::AirCraft => "Aircraft",
+            ObjectType::Ship => "Ship",
+            ObjectType::Unknown => "Unknown",
+            ObjectType::Hostile => "Hostile",
+            ObjectType::Generic => "Generic",
+            ObjectType::Weather => "Weather",
+        }
+    }
+
     pub fn symbol(&self) -> char {
         match self {
             ObjectType::AirCraft => '^',
@@ -387,6 +1596,28 @@ impl ObjectType {
         }
     }
 
+    /// Typical radar cross section in m².
+    pub fn rcs(&self) -> f64 {
+        match self {
+            ObjectType::AirCraft => 10.0,
+            ObjectType::Ship => 5000.0,
+            ObjectType::Unknown => 0.5,
+            ObjectType::Hostile => 5.0,
+            ObjectType::Generic => 2.0,
+            ObjectType::Weather => 200.0,
+        }
+    }
+
+    /// Default fluctuation model for the type.
+    pub fn swerling(&self) -> Swerling {
+        match self {
+            ObjectType::AirCraft | ObjectType::Unknown | ObjectType::Hostile => Swerling::I,
+            ObjectType::Ship => Swerling::III,
+            ObjectType::Generic => Swerling::Steady,
+            ObjectType::Weather => Swerling::II,
+        }
+    }
+
     pub fn color(&self) -> Color {
         match self {
             ObjectType::AirCraft => Color::Cyan,
@@ -398,3 +1629,507 @@ impl ObjectType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zone::ZoneShape;
+
+    /// Feed one scan's plots to the tracker and close the look at every track.
+    fn scan(radar: &mut RadarWidget, plots: &[Plot]) {
+        radar.tracker.process(plots, &radar.measurement);
+        radar.tracker.close_looks(|_| true);
+    }
+
+    #[test]
+    fn test_cycle_range_wraps() {
+        let mut radar = RadarWidget::new(1000.0, 10.0);
+        radar.set_range_scales(vec![250.0, 500.0, 1000.0]);
+        assert_eq!(radar.max_range, 1000.0);
+
+        radar.cycle_range();
+        assert_eq!(radar.max_range, 250.0);
+        radar.cycle_range();
+        assert_eq!(radar.max_range, 500.0);
+
+        // Objects are culled against the full coverage, not the display range
+        assert_eq!(radar.coverage_range, 1000.0);
+    }
+
+    #[test]
+    fn test_type_filter_hides_tracks() {
+        let mut radar = RadarWidget::new(1000.0, 10.0);
+        for (angle, object_type) in [(0.0, ObjectType::Ship), (90.0, ObjectType::Weather)] {
+            scan(
+                &mut radar,
+                &[Plot {
+                    object_type,
+                    ..Plot::at(angle, 100.0, 0.0)
+                }],
+            );
+        }
+
+        radar.toggle_type_filter(ObjectType::Weather);
+        assert_eq!(radar.visible_tracks().count(), 1);
+
+        radar.toggle_type_filter(ObjectType::Weather);
+        assert_eq!(radar.visible_tracks().count(), 2);
+    }
+
+    #[test]
+    fn test_same_seed_spawns_same_world() {
+        let spawn_all = |seed| {
+            let mut radar = RadarWidget::new(1000.0, 10.0).with_seed(seed);
+            for id in 0..20 {
+                radar.spawn_random_object(id);
+            }
+            radar
+                .world_objects
+                .iter()
+                .map(|obj| (obj.object_type, obj.x, obj.y, obj.heading, obj.speed))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(spawn_all(42), spawn_all(42));
+        assert_ne!(spawn_all(42), spawn_all(43));
+    }
+
+    #[test]
+    fn test_tracks_fade_on_sim_time() {
+        let mut radar = RadarWidget::new(1000.0, 10.0).with_seed(1);
+        radar
+            .world_objects
+            .push(WorldObjects::from_polar(1, ObjectType::Ship, 1.0, 100.0));
+
+        radar.update_sweep(0.05);
+        assert_eq!(radar.tracker.tracks.len(), 1);
+        let hit_time = radar.tracker.tracks[0].last_update_time;
+
+        // Half the fade duration later in sim time, regardless of wall clock
+        radar.world_objects.clear();
+        radar.update_sweep(5.0);
+        let track = &radar.tracker.tracks[0];
+        assert!((radar.sim_time() - hit_time - 5.0).abs() < 1e-9);
+        assert!((track.visibility - 0.5).abs() < 1e-9);
+
+        // A tentative track that misses its next looks never confirms
+        for _ in 0..200 {
+            radar.update_sweep(0.1);
+        }
+        assert!(radar.tracker.tracks.is_empty());
+        assert!(radar.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_lost_track_coasts_then_drops() {
+        let mut radar = RadarWidget::new(1000.0, 10.0).with_seed(1);
+        radar.world_objects.push(
+            WorldObjects::from_polar(1, ObjectType::Ship, 1.0, 150.0)
+                .with_swerling(Swerling::Steady),
+        );
+        // Three scans at 48 deg/s
+        for _ in 0..160 {
+            radar.update_sweep(0.1);
+        }
+        let id = radar.tracker.tracks[0].id;
+        assert_eq!(radar.tracker.tracks[0].status, TrackStatus::Confirmed);
+
+        radar.world_objects.clear();
+        for _ in 0..80 {
+            radar.update_sweep(0.1);
+        }
+        assert_eq!(radar.tracker.tracks[0].status, TrackStatus::Coasting);
+        for _ in 0..200 {
+            radar.update_sweep(0.1);
+        }
+        assert!(radar.tracker.tracks.is_empty());
+        assert_eq!(
+            radar.drain_events(),
+            vec![
+                SimEvent::Track(TrackEvent::Confirmed { id }),
+                SimEvent::Track(TrackEvent::Coasting { id }),
+                SimEvent::Track(TrackEvent::Lost { id }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_closing_track_raises_one_alert() {
+        let mut radar = RadarWidget::new(1000.0, 10.0).with_seed(2);
+        radar.world_objects.push(
+            WorldObjects::from_polar(1, ObjectType::Ship, 45.0, 400.0)
+                .with_motion(225.0, 4.0)
+                .with_swerling(Swerling::Steady),
+        );
+        for _ in 0..300 {
+            radar.update_world_objects(0.1);
+            radar.update_sweep(0.1);
+        }
+
+        let alerts: Vec<_> = radar
+            .drain_events()
+            .into_iter()
+            .filter(|event| matches!(event, SimEvent::CollisionAlert { .. }))
+            .collect();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(radar.alerts().len(), 1);
+    }
+
+    #[test]
+    fn test_guard_zone_entry_and_exit() {
+        let mut radar = RadarWidget::new(1000.0, 10.0).with_seed(3);
+        radar.zones = vec![Zone::new(
+            "Box",
+            ZoneKind::Guard,
+            ZoneShape::Polygon(vec![
+                (50.0, 250.0),
+                (150.0, 250.0),
+                (150.0, 350.0),
+                (50.0, 350.0),
+            ]),
+        )];
+        radar.world_objects.push(
+            WorldObjects::from_polar(1, ObjectType::Ship, 90.0, 300.0)
+                .with_motion(0.0, 5.0)
+                .with_swerling(Swerling::Steady),
+        );
+        for _ in 0..500 {
+            radar.update_world_objects(0.1);
+            radar.update_sweep(0.1);
+        }
+
+        let zone_events: Vec<_> = radar
+            .drain_events()
+            .into_iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    SimEvent::ZoneEntered { .. } | SimEvent::ZoneExited { .. }
+                )
+            })
+            .collect();
+        let id = radar.tracker.tracks[0].id;
+        assert_eq!(
+            zone_events,
+            vec![
+                SimEvent::ZoneEntered {
+                    zone: "Box".to_string(),
+                    id
+                },
+                SimEvent::ZoneExited {
+                    zone: "Box".to_string(),
+                    id
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exclusion_zone_suppresses_tracks() {
+        let mut radar = RadarWidget::new(1000.0, 10.0).with_seed(1);
+        radar.zones = vec![Zone::new(
+            "Clutter",
+            ZoneKind::Exclusion,
+            ZoneShape::Ring { radius: 200.0 },
+        )];
+        radar.world_objects.extend([
+            WorldObjects::from_polar(1, ObjectType::Ship, 30.0, 150.0),
+            WorldObjects::from_polar(2, ObjectType::Ship, 60.0, 400.0),
+        ]);
+        for _ in 0..80 {
+            radar.update_sweep(0.1);
+        }
+
+        assert_eq!(radar.tracker.tracks.len(), 1);
+        assert!(radar.tracker.tracks[0].distance_from((0.0, 0.0)) > 200.0);
+        // Raw video is still painted inside
+        assert!(radar.returns.iter().any(|ret| ret.distance < 200.0));
+    }
+
+    #[test]
+    fn test_constant_velocity_flies_straight() {
+        let mut obj =
+            WorldObjects::from_polar(1, ObjectType::AirCraft, 180.0, 500.0).with_motion(0.0, 10.0);
+        for _ in 0..100 {
+            obj.advance(1.0);
+        }
+        assert!((obj.x - 500.0).abs() < 1e-9);
+        assert!(obj.y.abs() < 1e-9);
+        assert!(obj.angle_from((0.0, 0.0)) < 1e-9 || obj.angle_from((0.0, 0.0)) > 360.0 - 1e-9);
+    }
+
+    #[test]
+    fn test_constant_turn_closes_circle() {
+        let mut obj = WorldObjects::from_polar(1, ObjectType::Hostile, 0.0, 100.0)
+            .with_motion(90.0, 5.0)
+            .with_turn_rate(10.0);
+        // 36 seconds at 10 deg/s is one full turn
+        for _ in 0..360 {
+            obj.advance(0.1);
+        }
+        assert!((obj.x - 100.0).abs() < 1e-6);
+        assert!(obj.y.abs() < 1e-6);
+        assert!((obj.heading - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_strong_targets_paint_sidelobes() {
+        let mut radar = RadarWidget::new(1000.0, 100.0).with_seed(1);
+        radar.world_objects.extend([
+            WorldObjects::from_polar(1, ObjectType::Ship, 90.0, 100.0)
+                .with_swerling(Swerling::Steady),
+            WorldObjects::from_polar(2, ObjectType::AirCraft, 270.0, 900.0)
+                .with_swerling(Swerling::Steady),
+        ]);
+        for _ in 0..100 {
+            radar.update_sweep(0.1);
+        }
+
+        let spread = |object_type| {
+            radar
+                .returns
+                .iter()
+                .filter(|ret| ret.object_type == object_type)
+                .map(|ret| angle_difference(ret.angle, 0.0))
+                .fold((f64::MAX, f64::MIN), |(lo, hi), a| (lo.min(a), hi.max(a)))
+        };
+        let (ship_lo, ship_hi) = spread(ObjectType::Ship);
+        let (air_lo, air_hi) = spread(ObjectType::AirCraft);
+
+        // The aircraft smears over part of the main lobe, the ship well into its sidelobes
+        assert!(air_hi - air_lo > RadarWidget::BEAM_WIDTH);
+        assert!(air_hi - air_lo < 3.0 * RadarWidget::BEAM_WIDTH);
+        assert!(ship_hi - ship_lo > 20.0);
+        assert_eq!(radar.tracker.tracks.len(), 2);
+    }
+
+    #[test]
+    fn test_objects_in_one_cell_merge() {
+        let mut radar = RadarWidget::new(1000.0, 100.0).with_seed(5);
+        radar.measurement.range_noise = 0.0;
+        radar.measurement.bearing_noise = 0.0;
+        radar.world_objects.extend([
+            // Same range bin and azimuth cell
+            WorldObjects::from_polar(1, ObjectType::Ship, 10.2, 300.5),
+            WorldObjects::from_polar(2, ObjectType::Generic, 10.7, 301.5),
+            // Next range bin over
+            WorldObjects::from_polar(3, ObjectType::Ship, 10.4, 306.0),
+        ]);
+        for _ in 0..10 {
+            radar.update_sweep(0.1);
+        }
+
+        let mut ranges: Vec<f64> = radar
+            .tracker
+            .tracks
+            .iter()
+            .map(|t| t.distance_from((0.0, 0.0)))
+            .collect();
+        ranges.sort_by(f64::total_cmp);
+        assert_eq!(ranges.len(), 2);
+        assert!((ranges[0] - 302.5).abs() < 1e-9);
+        assert!((ranges[1] - 307.5).abs() < 1e-9);
+        assert!((radar.tracker.tracks[0].angle_from((0.0, 0.0)) - 10.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bounds_keep_scope_round() {
+        let radar = RadarWidget::new(1000.0, 10.0);
+
+        // 100 columns by 50 rows is square on screen
+        let (x, y) = radar.bounds(Rect::new(0, 0, 100, 50));
+        assert_eq!((x, y), ([-1000.0, 1000.0], [-1000.0, 1000.0]));
+        assert_eq!(radar.scope_size(Rect::new(0, 0, 100, 50)), (100, 50));
+
+        // A wide pane shows extra range across and leaves spare columns
+        let (x, y) = radar.bounds(Rect::new(0, 0, 200, 50));
+        assert_eq!((x, y), ([-2000.0, 2000.0], [-1000.0, 1000.0]));
+        assert_eq!(radar.scope_size(Rect::new(0, 0, 200, 50)), (100, 50));
+
+        // A tall one extends up and down instead
+        let (x, y) = radar.bounds(Rect::new(0, 0, 50, 50));
+        assert_eq!((x, y), ([-1000.0, 1000.0], [-2000.0, 2000.0]));
+        assert_eq!(radar.scope_size(Rect::new(0, 0, 50, 50)), (50, 25));
+    }
+
+    #[test]
+    fn test_zoom_pan_and_center_on_selected() {
+        let mut radar = RadarWidget::new(100.0, 10.0);
+        let area = Rect::new(0, 0, 100, 50);
+
+        // A 20 unit window inside the 100 unit display range
+        radar.zoom_by(5.0);
+        assert_eq!(radar.bounds(area), ([-20.0, 20.0], [-20.0, 20.0]));
+        radar.pan(1.0, 0.0);
+        let ([left, right], _) = radar.bounds(area);
+        assert!((left + 18.0).abs() < 1e-9 && (right - 22.0).abs() < 1e-9);
+        radar.zoom_by(100.0);
+        assert_eq!(radar.zoom(), RadarWidget::MAX_ZOOM);
+
+        scan(&mut radar, &[Plot::at(90.0, 50.0, 0.0)]);
+        radar.select(Some(radar.tracker.tracks[0].id));
+        radar.center_on_selected();
+        let ([left, right], [bottom, top]) = radar.bounds(area);
+        assert!(((left + right) / 2.0).abs() < 1e-9);
+        assert!(((bottom + top) / 2.0 - 50.0).abs() < 1e-9);
+
+        // A track beyond the display range only takes the view to its edge
+        scan(&mut radar, &[Plot::at(0.0, 300.0, 1.0)]);
+        radar.select(radar.tracker.tracks.last().map(|track| track.id));
+        radar.center_on_selected();
+        let ([left, right], _) = radar.bounds(area);
+        assert!(((left + right) / 2.0 - 100.0).abs() < 1e-9);
+
+        radar.reset_view();
+        assert!(!radar.is_view_moved());
+    }
+
+    #[test]
+    fn test_selection_cycles_and_pins() {
+        let mut radar = RadarWidget::new(100.0, 10.0);
+        scan(
+            &mut radar,
+            &[Plot::at(10.0, 50.0, 0.0), Plot::at(200.0, 50.0, 0.0)],
+        );
+        // Tentative tracks cannot be selected
+        radar.cycle_selection(1);
+        assert_eq!(radar.selected(), None);
+        scan(
+            &mut radar,
+            &[Plot::at(10.0, 50.0, 1.0), Plot::at(200.0, 50.0, 1.0)],
+        );
+
+        radar.cycle_selection(1);
+        assert_eq!(radar.selected(), Some(1));
+        radar.cycle_selection(1);
+        assert_eq!(radar.selected(), Some(2));
+        radar.cycle_selection(1);
+        assert_eq!(radar.selected(), Some(1));
+        radar.cycle_selection(-1);
+        assert_eq!(radar.selected(), Some(2));
+
+        // Only the selected track outlives its misses
+        for _ in 0..5 {
+            scan(&mut radar, &[]);
+        }
+        let ids: Vec<u32> = radar.tracker.tracks.iter().map(|track| track.id).collect();
+        assert_eq!(ids, vec![2]);
+        radar.select(None);
+        scan(&mut radar, &[]);
+        assert!(radar.tracker.tracks.is_empty());
+    }
+
+    #[test]
+    fn test_screen_to_world_and_picking() {
+        let mut radar = RadarWidget::new(100.0, 10.0);
+        let area = Rect::new(0, 0, 100, 50);
+        // Each cell is 2 units wide and 4 tall; the middle cell sits just off center
+        assert_eq!(radar.screen_to_world(area, 50, 25), Some((1.0, -2.0)));
+        assert_eq!(radar.screen_to_world(area, 100, 25), None);
+
+        for time in [0.0, 1.0] {
+            scan(&mut radar, &[Plot::at(90.0, 50.0, time)]);
+        }
+        assert_eq!(radar.track_at(area, 50, 12), Some(1));
+        assert_eq!(radar.track_at(area, 60, 12), None);
+
+        // Turned and zoomed, the pointer still lands on the same world point
+        radar.set_own_ship(OwnShip::default().with_motion(0.0, 0.0));
+        radar.set_orientation(Orientation::HeadUp);
+        let (x, y) = radar.screen_to_world(area, 75, 12).unwrap();
+        let (back_x, back_y) = radar.from_display(radar.to_display((x, y)));
+        assert!((back_x - x).abs() < 1e-9 && (back_y - y).abs() < 1e-9);
+        radar.zoom_at(2.0, area, 75, 12);
+        let (zoomed_x, zoomed_y) = radar.screen_to_world(area, 75, 12).unwrap();
+        assert!((zoomed_x - x).abs() < 1e-9 && (zoomed_y - y).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spawns_fit_small_coverage() {
+        let mut radar = RadarWidget::new(10.0, 10.0);
+        for (id, object_type) in ObjectType::ALL.into_iter().enumerate() {
+            radar.spawn(id as u32, object_type);
+        }
+        assert_eq!(radar.world_objects.len(), ObjectType::ALL.len());
+        for obj in &radar.world_objects {
+            assert!(obj.distance_from((0.0, 0.0)) <= 10.0);
+        }
+    }
+
+    #[test]
+    fn test_sweep_stays_in_one_turn() {
+        let mut radar = RadarWidget::new(100.0, 10.0);
+        radar.rotation_rate = 2000.0;
+        for _ in 0..3 {
+            radar.update_sweep(1.0);
+            assert!((0.0..360.0).contains(&radar.sweep_angle));
+        }
+    }
+
+    #[test]
+    fn test_nautical_bearings() {
+        let nautical = BearingConvention::Nautical;
+        assert_eq!(nautical.from_math(0.0), 90.0);
+        assert_eq!(nautical.from_math(90.0), 0.0);
+        assert_eq!(nautical.from_math(180.0), 270.0);
+        assert_eq!(nautical.to_math(nautical.from_math(30.0)), 30.0);
+        assert_eq!(nautical.format(45.0), "045°");
+        // Rounds up past 359.5 to north rather than 360
+        assert_eq!(nautical.format(90.4), "000°");
+        assert_eq!(BearingConvention::Math.format(90.4), "090°");
+    }
+
+    #[test]
+    fn test_tracks_hold_still_while_own_ship_moves() {
+        let mut radar = RadarWidget::new(1000.0, 10.0).with_seed(4);
+        radar.set_own_ship(OwnShip::default().with_motion(0.0, 3.0));
+        radar
+            .world_objects
+            .push(WorldObjects::from_polar(1, ObjectType::Ship, 0.0, 400.0));
+        for _ in 0..600 {
+            radar.update_world_objects(0.1);
+            radar.update_sweep(0.1);
+        }
+
+        assert!((radar.own_ship.x - 180.0).abs() < 1e-6);
+        let track = &radar.tracker.tracks[0];
+        // Well short of own ship's 3 per second, which a relative track would show
+        assert!(track.speed() < 2.0, "speed {}", track.speed());
+        assert!((track.position().0 - 400.0).abs() < 10.0);
+        // Only own ship is moving, but it is heading straight for the track
+        assert!(track.alert);
+    }
+
+    #[test]
+    fn test_display_orientation_and_true_motion() {
+        let mut radar = RadarWidget::new(1000.0, 10.0);
+        radar.set_own_ship(OwnShip::default().with_motion(0.0, 10.0));
+        let close = |(x, y): (f64, f64), (ex, ey): (f64, f64)| {
+            (x - ex).abs() < 1e-6 && (y - ey).abs() < 1e-6
+        };
+
+        // Dead ahead is straight up in head-up and course-up
+        assert!(close(radar.to_display((100.0, 0.0)), (100.0, 0.0)));
+        radar.set_orientation(Orientation::HeadUp);
+        assert!(close(radar.to_display((100.0, 0.0)), (0.0, 100.0)));
+        radar.set_orientation(Orientation::CourseUp);
+        radar.own_ship.heading = 90.0;
+        assert!(close(radar.to_display((100.0, 0.0)), (0.0, 100.0)));
+        radar.set_orientation(Orientation::HeadUp);
+        assert!(close(radar.to_display((0.0, 100.0)), (0.0, 100.0)));
+
+        // True motion leaves the picture still until own ship nears the edge
+        radar.set_orientation(Orientation::NorthUp);
+        radar.set_true_motion(true);
+        radar.update_world_objects(50.0);
+        assert!(close(
+            radar.to_display(radar.own_ship.position()),
+            (0.0, 500.0)
+        ));
+        radar.update_world_objects(15.0);
+        assert!(close(
+            radar.to_display(radar.own_ship.position()),
+            (0.0, -500.0)
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::radar::angle_difference;
+
+/// What an object does once it reaches a waypoint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hold {
+    /// Stop in place for the given number of seconds.
+    Wait(f64),
+    /// Circle at `turn_rate` degrees per second for the given number of seconds.
+    Loiter { duration: f64, turn_rate: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waypoint {
+    pub x: f64,
+    pub y: f64,
+    /// Speed to fly the leg toward this waypoint, in range units per second.
+    pub speed: f64,
+    pub hold: Option<Hold>,
+}
+
+impl Waypoint {
+    pub fn new(x: f64, y: f64, speed: f64) -> Self {
+        Self {
+            x,
+            y,
+            speed,
+            hold: None,
+        }
+    }
+
+    pub fn with_hold(mut self, seconds: f64) -> Self {
+        self.hold = Some(Hold::Wait(seconds));
+        self
+    }
+
+    pub fn with_loiter(mut self, seconds: f64, turn_rate: f64) -> Self {
+        self.hold = Some(Hold::Loiter {
+            duration: seconds,
+            turn_rate,
+        });
+        self
+    }
+}
+
+/// A list of waypoints followed in order with a limited turn rate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub waypoints: Vec<Waypoint>,
+    /// Maximum heading change in degrees per second.
+    pub max_turn_rate: f64,
+    /// Distance at which a waypoint counts as reached.
+    pub arrival_radius: f64,
+    current: usize,
+    hold_remaining: Option<f64>,
+}
+
+/// Motion the route asks for over the next step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Steering {
+    /// Turn by at most `turn` degrees, then move at `speed`.
+    Leg { turn: f64, speed: f64 },
+    /// Stay put.
+    Wait,
+    /// Circle at the given turn rate, keeping the current speed.
+    Loiter { turn_rate: f64 },
+    /// The last waypoint has been reached and any hold there is over.
+    Finished,
+}
+
+impl Route {
+    pub const DEFAULT_MAX_TURN_RATE: f64 = 3.0;
+    pub const DEFAULT_ARRIVAL_RADIUS: f64 = 5.0;
+
+    pub fn new(waypoints: Vec<Waypoint>) -> Self {
+        Self {
+            waypoints,
+            max_turn_rate: Self::DEFAULT_MAX_TURN_RATE,
+            arrival_radius: Self::DEFAULT_ARRIVAL_RADIUS,
+            current: 0,
+            hold_remaining: None,
+        }
+    }
+
+    pub fn with_max_turn_rate(mut self, degrees_per_second: f64) -> Self {
+        self.max_turn_rate = degrees_per_second;
+        self
+    }
+
+    pub fn with_arrival_radius(mut self, radius: f64) -> Self {
+        self.arrival_radius = radius;
+        self
+    }
+
+    /// The waypoint currently being flown toward, if any.
+    pub fn active_waypoint(&self) -> Option<&Waypoint> {
+        self.waypoints.get(self.current)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.waypoints.len()
+    }
+
+    /// Decide how an object at `(x, y)` flying `heading` at `speed` should move for `delta_time`.
+    pub fn steer(&mut self, x: f64, y: f64, heading: f64, speed: f64, delta_time: f64) -> Steering {
+        if let Some(remaining) = self.hold_remaining {
+            let hold = self.waypoints[self.current].hold;
+            let remaining = remaining - delta_time;
+            if remaining > 0.0 {
+                self.hold_remaining = Some(remaining);
+                return match hold {
+                    Some(Hold::Loiter { turn_rate, .. }) => Steering::Loiter { turn_rate },
+                    _ => Steering::Wait,
+                };
+            }
+            self.hold_remaining = None;
+            self.current += 1;
+        }
+
+        let Some(waypoint) = self.waypoints.get(self.current) else {
+            return Steering::Finished;
+        };
+
+        let (dx, dy) = (waypoint.x - x, waypoint.y - y);
+        let distance = dx.hypot(dy);
+        if distance <= self.arrival_radius.max(speed * delta_time) {
+            match waypoint.hold {
+                Some(Hold::Wait(seconds))
+                | Some(Hold::Loiter {
+                    duration: seconds, ..
+                }) => {
+                    self.hold_remaining = Some(seconds);
+                }
+                None => self.current += 1,
+            }
+            return self.steer(x, y, heading, speed, 0.0);
+        }
+
+        let mut error = angle_difference(dy.atan2(dx).to_degrees(), heading);
+
+        // A waypoint inside the turning circle can never be reached by turning
+        // toward it, so hold course until it falls outside.
+        if self.max_turn_rate > 0.0 && waypoint.speed > 0.0 {
+            let radius = waypoint.speed / self.max_turn_rate.to_radians();
+            let side = error.signum();
+            let rad = heading.to_radians();
+            let center_x = x - side * radius * rad.sin();
+            let center_y = y + side * radius * rad.cos();
+            if (waypoint.x - center_x).hypot(waypoint.y - center_y) < radius {
+                error = 0.0;
+            }
+        }
+
+        let max_turn = self.max_turn_rate * delta_time;
+        Steering::Leg {
+            turn: error.clamp(-max_turn, max_turn),
+            speed: waypoint.speed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::radar::{ObjectType, SimEvent, WorldObjects};
+
+    fn fly(obj: &mut WorldObjects, seconds: f64) -> Vec<SimEvent> {
+        let steps = (seconds * 10.0) as usize;
+        (0..steps).filter_map(|_| obj.advance(0.1)).collect()
+    }
+
+    #[test]
+    fn test_route_reaches_waypoints_and_finishes() {
+        let route = Route::new(vec![
+            Waypoint::new(100.0, 0.0, 10.0),
+            Waypoint::new(100.0, 100.0, 10.0),
+        ])
+        .with_max_turn_rate(30.0);
+        let mut obj = WorldObjects::from_polar(7, ObjectType::Ship, 0.0, 0.0).with_route(route);
+
+        let events = fly(&mut obj, 60.0);
+
+        assert_eq!(events, vec![SimEvent::RouteFinished { id: 7 }]);
+        assert!(obj.route.is_none());
+        // Past the last waypoint, still heading roughly north
+        assert!(obj.y > 100.0);
+        assert!((obj.heading - 90.0).abs() < 15.0);
+    }
+
+    #[test]
+    fn test_turn_rate_is_limited() {
+        let route = Route::new(vec![Waypoint::new(-500.0, 0.0, 10.0)]).with_max_turn_rate(5.0);
+        let mut obj = WorldObjects::from_polar(1, ObjectType::AirCraft, 0.0, 0.0)
+            .with_motion(0.0, 10.0)
+            .with_route(route);
+
+        // Dead astern, so either direction is fine but only 5 degrees of it
+        fly(&mut obj, 1.0);
+        assert!((angle_difference(obj.heading, 0.0).abs() - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_hold_waits_in_place() {
+        let route = Route::new(vec![Waypoint::new(10.0, 0.0, 10.0).with_hold(5.0)]);
+        let mut obj = WorldObjects::from_polar(1, ObjectType::Ship, 0.0, 0.0)
+            .with_motion(0.0, 10.0)
+            .with_route(route);
+
+        assert!(fly(&mut obj, 3.0).is_empty());
+        let (x, y) = (obj.x, obj.y);
+        assert!(fly(&mut obj, 2.0).is_empty());
+        assert_eq!((obj.x, obj.y), (x, y));
+
+        assert_eq!(fly(&mut obj, 1.0), vec![SimEvent::RouteFinished { id: 1 }]);
+    }
+}
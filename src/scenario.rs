@@ -0,0 +1,437 @@
+use crate::detection::Swerling;
+use crate::radar::{ObjectType, RadarWidget, WorldObjects};
+use crate::route::{Route, Waypoint};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+use std::{error::Error, fmt, fs, io};
+
+/// Errors that can occur when loading a scenario file.
+#[derive(Debug)]
+pub enum ScenarioError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file is not valid TOML or does not match the scenario layout.
+    Parse(toml::de::Error),
+    /// The file parsed but an entry makes no sense, e.g. `objects[2]`.
+    Invalid { entry: String, message: String },
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::Io(e) => write!(f, "Failed to read scenario: {e}"),
+            ScenarioError::Parse(e) => write!(f, "Failed to parse scenario: {e}"),
+            ScenarioError::Invalid { entry, message } => write!(f, "{entry}: {message}"),
+        }
+    }
+}
+
+impl Error for ScenarioError {}
+
+impl From<io::Error> for ScenarioError {
+    fn from(e: io::Error) -> Self {
+        ScenarioError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ScenarioError {
+    fn from(e: toml::de::Error) -> Self {
+        ScenarioError::Parse(e)
+    }
+}
+
+/// A scripted run: radar parameters, the objects present at the start and a timeline of changes.
+///
+/// # Example
+///
+/// ```
+/// use tui_radar_sim_core::scenario::Scenario;
+///
+/// let scenario = Scenario::from_toml_str(r#"
+///     [radar]
+///     max_range = 500.0
+///
+///     [[objects]]
+///     id = 1
+///     type = "ship"
+///     range = 100.0
+///     bearing = 45.0
+///     heading = 180.0
+///     speed = 1.5
+///
+///     [[timeline]]
+///     time = 30.0
+///     remove = 1
+/// "#).unwrap();
+///
+/// assert_eq!(scenario.objects.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    #[serde(default)]
+    pub radar: RadarSettings,
+    /// Keep the periodic random spawner running alongside the timeline.
+    #[serde(default)]
+    pub random_traffic: bool,
+    #[serde(default)]
+    pub objects: Vec<ObjectSpec>,
+    #[serde(default)]
+    pub timeline: Vec<TimelineEntry>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RadarSettings {
+    pub max_range: Option<f64>,
+    /// Degrees per second.
+    pub rotation_rate: Option<f64>,
+    /// Seconds for a track to fade after its last update.
+    pub fade_duration: Option<f64>,
+    pub range_scales: Option<Vec<f64>>,
+    /// Own ship heading in degrees, 0 = east, counter-clockwise.
+    pub own_heading: Option<f64>,
+    /// Own ship speed in range units per second.
+    pub own_speed: Option<f64>,
+    /// Own ship rate of turn in degrees per second.
+    pub own_turn_rate: Option<f64>,
+}
+
+/// An object, placed either by `x`/`y` or by `range`/`bearing` from where the radar starts.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectSpec {
+    pub id: u32,
+    #[serde(rename = "type")]
+    pub object_type: ObjectType,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub range: Option<f64>,
+    pub bearing: Option<f64>,
+    #[serde(default)]
+    pub heading: f64,
+    #[serde(default)]
+    pub speed: f64,
+    #[serde(default)]
+    pub turn_rate: f64,
+    /// Radar cross section in m²; defaults to the type's typical value.
+    pub rcs: Option<f64>,
+    pub swerling: Option<Swerling>,
+    pub route: Option<RouteSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RouteSpec {
+    pub max_turn_rate: Option<f64>,
+    pub arrival_radius: Option<f64>,
+    pub waypoints: Vec<WaypointSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WaypointSpec {
+    pub x: f64,
+    pub y: f64,
+    pub speed: f64,
+    /// Seconds to stop at the waypoint.
+    pub hold: Option<f64>,
+    pub loiter: Option<LoiterSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LoiterSpec {
+    pub duration: f64,
+    pub turn_rate: f64,
+}
+
+/// Something that happens `time` seconds into the scenario: exactly one of `spawn` or `remove`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimelineEntry {
+    pub time: f64,
+    pub spawn: Option<ObjectSpec>,
+    pub remove: Option<u32>,
+}
+
+impl Scenario {
+    /// Read, parse and validate a scenario file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError> {
+        let text = fs::read_to_string(path)?;
+        Self::from_toml_str(&text)
+    }
+
+    /// Parse and validate a scenario; the timeline is sorted by time.
+    pub fn from_toml_str(text: &str) -> Result<Self, ScenarioError> {
+        let mut scenario: Scenario = toml::from_str(text)?;
+        scenario.validate()?;
+        scenario.timeline.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Ok(scenario)
+    }
+
+    fn validate(&self) -> Result<(), ScenarioError> {
+        let radar = &self.radar;
+        positive("radar", "max_range", radar.max_range)?;
+        positive("radar", "rotation_rate", radar.rotation_rate)?;
+        positive("radar", "fade_duration", radar.fade_duration)?;
+        if let Some(scales) = &radar.range_scales {
+            if scales.is_empty() {
+                return Err(invalid("radar", "range_scales must not be empty"));
+            }
+            for (i, &scale) in scales.iter().enumerate() {
+                positive("radar", &format!("range_scales[{i}]"), Some(scale))?;
+            }
+        }
+
+        let mut ids = HashSet::new();
+        for (i, object) in self.objects.iter().enumerate() {
+            let entry = format!("objects[{i}] (id {})", object.id);
+            object.validate(&entry)?;
+            if !ids.insert(object.id) {
+                return Err(invalid(&entry, "duplicate id"));
+            }
+        }
+
+        for (i, event) in self.timeline.iter().enumerate() {
+            let entry = format!("timeline[{i}]");
+            if !event.time.is_finite() || event.time < 0.0 {
+                return Err(invalid(&entry, "time must be zero or positive"));
+            }
+            match (&event.spawn, event.remove) {
+                (Some(object), None) => {
+                    let entry = format!("{entry}.spawn (id {})", object.id);
+                    object.validate(&entry)?;
+                    if !ids.insert(object.id) {
+                        return Err(invalid(&entry, "duplicate id"));
+                    }
+                }
+                (None, Some(_)) => {}
+                _ => return Err(invalid(&entry, "needs exactly one of spawn or remove")),
+            }
+        }
+
+        // Removals may only target objects declared somewhere in the scenario
+        for (i, event) in self.timeline.iter().enumerate() {
+            if let Some(id) = event.remove
+                && !ids.contains(&id)
+            {
+                return Err(invalid(
+                    &format!("timeline[{i}]"),
+                    &format!("remove refers to unknown id {id}"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Place the initial objects into the radar's world.
+    pub fn populate(&self, radar: &mut RadarWidget) {
+        radar
+            .world_objects
+            .extend(self.objects.iter().map(ObjectSpec::build));
+    }
+}
+
+impl TimelineEntry {
+    pub fn apply(&self, radar: &mut RadarWidget) {
+        if let Some(object) = &self.spawn {
+            radar.world_objects.push(object.build());
+        }
+        if let Some(id) = self.remove {
+            radar.world_objects.retain(|obj| obj.id != id);
+        }
+    }
+}
+
+impl ObjectSpec {
+    fn validate(&self, entry: &str) -> Result<(), ScenarioError> {
+        // Random spawns are numbered after the largest scenario id
+        if self.id == u32::MAX {
+            return Err(invalid(entry, &format!("id must be below {}", u32::MAX)));
+        }
+        match (self.x, self.y, self.range, self.bearing) {
+            (Some(_), Some(_), None, None) => {}
+            (None, None, Some(range), Some(_)) => positive(entry, "range", Some(range))?,
+            _ => return Err(invalid(entry, "give either x and y, or range and bearing")),
+        }
+        if self.speed.is_nan() || self.speed < 0.0 {
+            return Err(invalid(entry, "speed must be zero or positive"));
+        }
+        positive(entry, "rcs", self.rcs)?;
+
+        if let Some(route) = &self.route {
+            if route.waypoints.is_empty() {
+                return Err(invalid(entry, "route needs at least one waypoint"));
+            }
+            positive(entry, "route.max_turn_rate", route.max_turn_rate)?;
+            positive(entry, "route.arrival_radius", route.arrival_radius)?;
+            for (i, waypoint) in route.waypoints.iter().enumerate() {
+                let field = format!("route.waypoints[{i}]");
+                positive(entry, &format!("{field}.speed"), Some(waypoint.speed))?;
+                if waypoint.hold.is_some() && waypoint.loiter.is_some() {
+                    return Err(invalid(entry, &format!("{field} has both hold and loiter")));
+                }
+                positive(entry, &format!("{field}.hold"), waypoint.hold)?;
+                if let Some(loiter) = &waypoint.loiter {
+                    positive(
+                        entry,
+                        &format!("{field}.loiter.duration"),
+                        Some(loiter.duration),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn build(&self) -> WorldObjects {
+        let object = match (self.x, self.y, self.range, self.bearing) {
+            (Some(x), Some(y), _, _) => {
+                let mut object = WorldObjects::from_polar(self.id, self.object_type, 0.0, 0.0);
+                object.x = x;
+                object.y = y;
+                object
+            }
+            _ => WorldObjects::from_polar(
+                self.id,
+                self.object_type,
+                self.bearing.unwrap_or_default(),
+                self.range.unwrap_or_default(),
+            ),
+        }
+        .with_motion(self.heading, self.speed)
+        .with_turn_rate(self.turn_rate);
+        let object = match self.rcs {
+            Some(rcs) => object.with_rcs(rcs),
+            None => object,
+        };
+        let object = match self.swerling {
+            Some(swerling) => object.with_swerling(swerling),
+            None => object,
+        };
+
+        match &self.route {
+            Some(route) => object.with_route(route.build()),
+            None => object,
+        }
+    }
+}
+
+impl RouteSpec {
+    fn build(&self) -> Route {
+        let waypoints = self
+            .waypoints
+            .iter()
+            .map(|spec| {
+                let waypoint = Waypoint::new(spec.x, spec.y, spec.speed);
+                match (spec.hold, &spec.loiter) {
+                    (Some(seconds), _) => waypoint.with_hold(seconds),
+                    (None, Some(loiter)) => waypoint.with_loiter(loiter.duration, loiter.turn_rate),
+                    (None, None) => waypoint,
+                }
+            })
+            .collect();
+
+        let mut route = Route::new(waypoints);
+        if let Some(rate) = self.max_turn_rate {
+            route = route.with_max_turn_rate(rate);
+        }
+        if let Some(radius) = self.arrival_radius {
+            route = route.with_arrival_radius(radius);
+        }
+        route
+    }
+}
+
+fn invalid(entry: &str, message: &str) -> ScenarioError {
+    ScenarioError::Invalid {
+        entry: entry.to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn positive(entry: &str, field: &str, value: Option<f64>) -> Result<(), ScenarioError> {
+    match value {
+        Some(v) if !(v > 0.0 && v.is_finite()) => Err(invalid(
+            entry,
+            &format!("{field} must be a positive number"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_scenario_loads() {
+        let scenario = Scenario::from_toml_str(include_str!("../scenarios/approach.toml")).unwrap();
+        assert!(!scenario.objects.is_empty());
+        assert!(
+            scenario
+                .timeline
+                .windows(2)
+                .all(|pair| pair[0].time <= pair[1].time)
+        );
+    }
+
+    #[test]
+    fn test_validation_points_at_entry() {
+        let err = Scenario::from_toml_str(
+            r#"
+            [[objects]]
+            id = 1
+            type = "ship"
+            x = 10.0
+            y = 10.0
+
+            [[objects]]
+            id = 2
+            type = "aircraft"
+            x = 10.0
+            range = 50.0
+            "#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "objects[1] (id 2): give either x and y, or range and bearing"
+        );
+    }
+
+    #[test]
+    fn test_largest_id_rejected() {
+        let err = Scenario::from_toml_str(&format!(
+            r#"
+            [[objects]]
+            id = {}
+            type = "ship"
+            x = 0.0
+            y = 0.0
+            "#,
+            u32::MAX
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("objects[0] (id {0}): id must be below {0}", u32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_unknown_removal_rejected() {
+        let err = Scenario::from_toml_str(
+            r#"
+            [[timeline]]
+            time = 10.0
+            remove = 9
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ScenarioError::Invalid { ref entry, .. } if entry == "timeline[0]"));
+    }
+}
@@ -0,0 +1,783 @@
+use crate::detection::MeasurementModel;
+use crate::own_ship::OwnShip;
+use crate::radar::{ObjectType, Plot, normalize_angle};
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::fmt;
+
+type Matrix = [[f64; 4]; 4];
+
+/// A target estimate kept by a constant-velocity Kalman filter.
+///
+/// The state is `[x, y, vx, vy]` in the world frame, so tracks hold still on
+/// the ground while the radar moves. See
+/// [`BearingConvention`](crate::radar::BearingConvention) for the frame and
+/// angle conventions.
+#[derive(Debug, Clone)]
+pub struct Track {
+    /// Assigned by the tracker; unrelated to any simulated object's id.
+    pub id: u32,
+    pub status: TrackStatus,
+    /// Classification of the last plot folded into the track.
+    pub object_type: ObjectType,
+    pub state: [f64; 4],
+    pub covariance: Matrix,
+    /// Simulation time the state has been predicted to, in seconds.
+    pub time: f64,
+    /// Simulation time the track was started, in seconds.
+    pub start_time: f64,
+    /// Simulation time of the last plot folded into the track, in seconds.
+    pub last_update_time: f64,
+    pub visibility: f64,
+    /// Received power of the last plot, in noise units.
+    pub amplitude: f64,
+    /// Plots folded in so far, including the one that started the track.
+    pub updates: u32,
+    /// Recent plot positions as `(x, y, time)`, oldest first.
+    pub history: VecDeque<(f64, f64, f64)>,
+    /// Whether the track's closest approach breaks the alert limits.
+    pub alert: bool,
+    /// Keep coasting however many looks are missed, e.g. while the operator has it selected.
+    pub pinned: bool,
+    /// Looks judged while tentative, for M-of-N confirmation.
+    looks: u32,
+    /// Looks with a plot while tentative.
+    hits: u32,
+    /// Consecutive looks without a plot.
+    pub misses: u32,
+    /// Whether a plot has been folded in since the last look was judged.
+    updated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackStatus {
+    /// Started from a single plot that may have been noise.
+    Tentative,
+    /// Enough plots in the first looks to be a real target.
+    Confirmed,
+    /// Confirmed but missed on recent looks, moving on its last estimate.
+    Coasting,
+}
+
+impl TrackStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TrackStatus::Tentative => "Tentative",
+            TrackStatus::Confirmed => "Confirmed",
+            TrackStatus::Coasting => "Coasting",
+        }
+    }
+}
+
+/// A change in a track's lifecycle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackEvent {
+    Confirmed { id: u32 },
+    Coasting { id: u32 },
+    Lost { id: u32 },
+}
+
+impl fmt::Display for TrackEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackEvent::Confirmed { id } => write!(f, "New track T{id}"),
+            TrackEvent::Coasting { id } => write!(f, "Track T{id} coasting"),
+            TrackEvent::Lost { id } => write!(f, "Track T{id} lost"),
+        }
+    }
+}
+
+/// How plots are matched to tracks once they pass the gate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Association {
+    /// Each plot in turn takes the closest free track, best pairs first.
+    Nearest,
+    /// Global nearest neighbor: the assignment with the lowest total distance.
+    #[default]
+    Global,
+}
+
+impl Track {
+    /// Start a tentative track on a single plot, with the velocity only known to within `speed_error`.
+    pub fn new(id: u32, plot: &Plot, measurement: &MeasurementModel, speed_error: f64) -> Self {
+        let (x, y) = plot.position();
+        let r = measurement.covariance(plot.angle, plot.distance);
+        let variance = speed_error * speed_error;
+        Self {
+            id,
+            status: TrackStatus::Tentative,
+            object_type: plot.object_type,
+            state: [x, y, 0.0, 0.0],
+            covariance: [
+                [r[0][0], r[0][1], 0.0, 0.0],
+                [r[1][0], r[1][1], 0.0, 0.0],
+                [0.0, 0.0, variance, 0.0],
+                [0.0, 0.0, 0.0, variance],
+            ],
+            time: plot.time,
+            start_time: plot.time,
+            last_update_time: plot.time,
+            visibility: 1.0,
+            amplitude: plot.amplitude,
+            updates: 1,
+            history: VecDeque::from([(x, y, plot.time)]),
+            alert: false,
+            pinned: false,
+            looks: 0,
+            hits: 0,
+            misses: 0,
+            updated: true,
+        }
+    }
+
+    /// Dead-reckon the state forward to `time`, growing the covariance by the
+    /// white-acceleration `process_noise`.
+    pub fn predict(&mut self, time: f64, process_noise: f64) {
+        let dt = time - self.time;
+        if dt <= 0.0 {
+            return;
+        }
+        self.state[0] += self.state[2] * dt;
+        self.state[1] += self.state[3] * dt;
+
+        let transition = [
+            [1.0, 0.0, dt, 0.0],
+            [0.0, 1.0, 0.0, dt],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+        let mut covariance = multiply(
+            &multiply(&transition, &self.covariance),
+            &transpose(&transition),
+        );
+        let (q11, q12, q22) = (
+            process_noise * dt.powi(3) / 3.0,
+            process_noise * dt.powi(2) / 2.0,
+            process_noise * dt,
+        );
+        for axis in 0..2 {
+            covariance[axis][axis] += q11;
+            covariance[axis][axis + 2] += q12;
+            covariance[axis + 2][axis] += q12;
+            covariance[axis + 2][axis + 2] += q22;
+        }
+        self.covariance = covariance;
+        self.time = time;
+    }
+
+    /// Difference between the plot and the predicted position, with the
+    /// inverse of its covariance.
+    fn innovation(&self, plot: &Plot, measurement: &MeasurementModel) -> ([f64; 2], [[f64; 2]; 2]) {
+        let (x, y) = plot.position();
+        let r = measurement.covariance(plot.angle, plot.distance);
+        let p = &self.covariance;
+        let s = [
+            [p[0][0] + r[0][0], p[0][1] + r[0][1]],
+            [p[1][0] + r[1][0], p[1][1] + r[1][1]],
+        ];
+        let det = s[0][0] * s[1][1] - s[0][1] * s[1][0];
+        let s_inv = [
+            [s[1][1] / det, -s[0][1] / det],
+            [-s[1][0] / det, s[0][0] / det],
+        ];
+        ([x - self.state[0], y - self.state[1]], s_inv)
+    }
+
+    /// Squared Mahalanobis distance from the predicted position to `plot`.
+    pub fn distance_to(&self, plot: &Plot, measurement: &MeasurementModel) -> f64 {
+        let (v, s_inv) = self.innovation(plot, measurement);
+        v[0] * (s_inv[0][0] * v[0] + s_inv[0][1] * v[1])
+            + v[1] * (s_inv[1][0] * v[0] + s_inv[1][1] * v[1])
+    }
+
+    /// Fold in a plot taken at the track's current time.
+    pub fn update(&mut self, plot: &Plot, measurement: &MeasurementModel) {
+        let (innovation, s_inv) = self.innovation(plot, measurement);
+        if !s_inv.iter().flatten().all(|value| value.is_finite()) {
+            return;
+        }
+        let (x, y) = plot.position();
+        self.history.push_back((x, y, plot.time));
+        let p = self.covariance;
+
+        let mut gain = [[0.0; 2]; 4];
+        for (row, gain_row) in gain.iter_mut().enumerate() {
+            for (col, value) in gain_row.iter_mut().enumerate() {
+                *value = p[row][0] * s_inv[0][col] + p[row][1] * s_inv[1][col];
+            }
+        }
+
+        for (row, gain_row) in gain.iter().enumerate() {
+            self.state[row] += gain_row[0] * innovation[0] + gain_row[1] * innovation[1];
+        }
+        for (row, gain_row) in gain.iter().enumerate() {
+            for (col, value) in self.covariance[row].iter_mut().enumerate() {
+                *value -= gain_row[0] * p[0][col] + gain_row[1] * p[1][col];
+            }
+        }
+
+        self.last_update_time = plot.time;
+        self.visibility = 1.0;
+        self.amplitude = plot.amplitude;
+        self.object_type = plot.object_type;
+        self.updates += 1;
+        self.updated = true;
+    }
+
+    pub fn position(&self) -> (f64, f64) {
+        (self.state[0], self.state[1])
+    }
+
+    pub fn velocity(&self) -> (f64, f64) {
+        (self.state[2], self.state[3])
+    }
+
+    /// Bearing from `origin` in degrees, in `0.0..360.0`.
+    pub fn angle_from(&self, (x, y): (f64, f64)) -> f64 {
+        normalize_angle((self.state[1] - y).atan2(self.state[0] - x).to_degrees())
+    }
+
+    /// Range from `origin`.
+    pub fn distance_from(&self, (x, y): (f64, f64)) -> f64 {
+        (self.state[0] - x).hypot(self.state[1] - y)
+    }
+
+    /// Estimated course over ground in degrees, in `0.0..360.0`.
+    pub fn course(&self) -> f64 {
+        normalize_angle(self.state[3].atan2(self.state[2]).to_degrees())
+    }
+
+    /// Estimated speed in range units per second.
+    pub fn speed(&self) -> f64 {
+        self.state[2].hypot(self.state[3])
+    }
+
+    /// Semi-major axis of the one-sigma position error ellipse, in range units.
+    pub fn position_error(&self) -> f64 {
+        largest_eigenvalue(&self.covariance, 0).sqrt()
+    }
+
+    /// Semi-major axis of the one-sigma velocity error ellipse, in range units per second.
+    pub fn velocity_error(&self) -> f64 {
+        largest_eigenvalue(&self.covariance, 2).sqrt()
+    }
+
+    /// Closest point of approach to own ship if both hold their course and speed.
+    pub fn approach(&self, own_ship: &OwnShip) -> Approach {
+        // Work in own ship's frame, where only the relative motion matters
+        let (x, y) = (self.state[0] - own_ship.x, self.state[1] - own_ship.y);
+        let (own_vx, own_vy) = own_ship.velocity();
+        let (vx, vy) = (self.state[2] - own_vx, self.state[3] - own_vy);
+        let speed_squared = vx * vx + vy * vy;
+        let tcpa = if speed_squared > f64::EPSILON {
+            -(x * vx + y * vy) / speed_squared
+        } else {
+            0.0
+        };
+        Approach {
+            cpa: (x + vx * tcpa).hypot(y + vy * tcpa),
+            tcpa,
+        }
+    }
+}
+
+/// Closest point of approach of a track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Approach {
+    /// Distance at the closest point, in range units.
+    pub cpa: f64,
+    /// Seconds until the closest point; negative once it has passed.
+    pub tcpa: f64,
+}
+
+impl fmt::Display for Approach {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self.tcpa.max(0.0).round() as u64;
+        write!(
+            f,
+            "CPA {:.0} in {}:{:02}",
+            self.cpa,
+            seconds / 60,
+            seconds % 60
+        )
+    }
+}
+
+/// Associates each scan's plots with tracks and runs the track filters.
+///
+/// Plots carry no hint of which object they came from, so crossing or
+/// closely spaced objects can swap tracks or spawn false ones.
+#[derive(Debug, Clone)]
+pub struct Tracker {
+    pub tracks: Vec<Track>,
+    /// Spectral density of the white acceleration noise, in range units² per s³.
+    pub process_noise: f64,
+    /// One-sigma speed uncertainty of a track started from a single plot.
+    pub initial_speed_error: f64,
+    /// Largest squared Mahalanobis distance at which a plot may update a track.
+    pub gate: f64,
+    pub association: Association,
+    /// M of M-of-N: looks with a plot needed to confirm a tentative track.
+    pub confirm_hits: u32,
+    /// N of M-of-N: looks a tentative track gets to reach `confirm_hits`.
+    pub confirm_looks: u32,
+    /// Consecutive missed looks after which a confirmed track is dropped.
+    pub drop_misses: u32,
+    /// Plot positions kept in each track's history.
+    pub history_length: usize,
+    next_id: u32,
+}
+
+impl Default for Tracker {
+    fn default() -> Self {
+        Self {
+            tracks: Vec::new(),
+            process_noise: 1.0,
+            initial_speed_error: 20.0,
+            // 99% of true plots fall inside for two measured dimensions
+            gate: 9.21,
+            association: Association::default(),
+            confirm_hits: 2,
+            confirm_looks: 3,
+            drop_misses: 3,
+            history_length: 10,
+            next_id: 1,
+        }
+    }
+}
+
+impl Tracker {
+    /// Dead-reckon every track to `time`.
+    pub fn predict(&mut self, time: f64) {
+        for track in &mut self.tracks {
+            track.predict(time, self.process_noise);
+        }
+    }
+
+    /// Associate a batch of plots with the tracks, update the matched tracks
+    /// and start a tentative track on every plot left over.
+    ///
+    /// Each track takes at most one plot from the batch.
+    pub fn process(&mut self, plots: &[Plot], measurement: &MeasurementModel) {
+        if plots.is_empty() {
+            return;
+        }
+        // Squared distances, `None` outside the gate. A pinned track kept past
+        // `drop_misses` is only held for display: its gate has grown too wide
+        // to trust, so it takes no plots.
+        let distances: Vec<Vec<Option<f64>>> = plots
+            .iter()
+            .map(|plot| {
+                self.tracks
+                    .iter()
+                    .map(|track| {
+                        let distance = track.distance_to(plot, measurement);
+                        (distance <= self.gate && track.misses < self.drop_misses)
+                            .then_some(distance)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let assignment = match self.association {
+            Association::Nearest => assign_nearest(&distances),
+            Association::Global => assign_global(&distances, self.gate),
+        };
+
+        for (plot, track) in plots.iter().zip(assignment) {
+            match track {
+                Some(index) => {
+                    let track = &mut self.tracks[index];
+                    track.predict(plot.time, self.process_noise);
+                    track.update(plot, measurement);
+                }
+                None => {
+                    let id = self.next_id;
+                    self.next_id += 1;
+                    self.tracks
+                        .push(Track::new(id, plot, measurement, self.initial_speed_error));
+                }
+            }
+        }
+
+        for track in &mut self.tracks {
+            let excess = track.history.len().saturating_sub(self.history_length);
+            track.history.drain(..excess);
+        }
+    }
+
+    /// Judge the look at every track `swept` reports the beam has just passed:
+    /// promote, coast or drop it depending on whether a plot arrived since its
+    /// previous look.
+    pub fn close_looks(&mut self, swept: impl Fn(&Track) -> bool) -> Vec<TrackEvent> {
+        let mut events = Vec::new();
+        let (confirm_hits, confirm_looks, drop_misses) =
+            (self.confirm_hits, self.confirm_looks, self.drop_misses);
+        self.tracks.retain_mut(|track| {
+            if !swept(track) {
+                return true;
+            }
+            let hit = std::mem::take(&mut track.updated);
+            track.misses = if hit { 0 } else { track.misses + 1 };
+
+            match track.status {
+                TrackStatus::Tentative => {
+                    track.looks += 1;
+                    track.hits += u32::from(hit);
+                    if track.hits >= confirm_hits {
+                        track.status = TrackStatus::Confirmed;
+                        events.push(TrackEvent::Confirmed { id: track.id });
+                    } else if track.hits + confirm_looks.saturating_sub(track.looks) < confirm_hits
+                    {
+                        // Can no longer make M of N; quietly forget it
+                        return false;
+                    }
+                }
+                TrackStatus::Confirmed | TrackStatus::Coasting if hit => {
+                    track.status = TrackStatus::Confirmed;
+                }
+                TrackStatus::Confirmed | TrackStatus::Coasting => {
+                    if track.misses >= drop_misses && !track.pinned {
+                        events.push(TrackEvent::Lost { id: track.id });
+                        return false;
+                    }
+                    if track.status == TrackStatus::Confirmed {
+                        track.status = TrackStatus::Coasting;
+                        events.push(TrackEvent::Coasting { id: track.id });
+                    }
+                }
+            }
+            true
+        });
+        events
+    }
+
+    /// Drop every track and restart track numbering.
+    pub fn clear(&mut self) {
+        self.tracks.clear();
+        self.next_id = 1;
+    }
+}
+
+/// Greedy association: the closest gated plot-track pair is matched first.
+fn assign_nearest(distances: &[Vec<Option<f64>>]) -> Vec<Option<usize>> {
+    let mut pairs: Vec<(f64, usize, usize)> = distances
+        .iter()
+        .enumerate()
+        .flat_map(|(plot, row)| {
+            row.iter()
+                .enumerate()
+                .filter_map(move |(track, distance)| distance.map(|d| (d, plot, track)))
+        })
+        .collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut assignment = vec![None; distances.len()];
+    let mut taken = vec![false; distances.first().map_or(0, Vec::len)];
+    for (_, plot, track) in pairs {
+        if assignment[plot].is_none() && !taken[track] {
+            assignment[plot] = Some(track);
+            taken[track] = true;
+        }
+    }
+    assignment
+}
+
+/// Global nearest neighbor: minimize the summed distance over the whole batch.
+///
+/// Every plot also gets a private "new track" option costing `gate`, so a
+/// plot is only left unassigned when that is cheaper overall.
+fn assign_global(distances: &[Vec<Option<f64>>], gate: f64) -> Vec<Option<usize>> {
+    let tracks = distances.first().map_or(0, Vec::len);
+    let forbidden = gate * 1e6;
+    let cost: Vec<Vec<f64>> = distances
+        .iter()
+        .enumerate()
+        .map(|(plot, row)| {
+            let real = row.iter().map(|distance| distance.unwrap_or(forbidden));
+            let new_track =
+                (0..distances.len()).map(|other| if other == plot { gate } else { forbidden });
+            real.chain(new_track).collect()
+        })
+        .collect();
+
+    hungarian(&cost)
+        .into_iter()
+        .enumerate()
+        .map(|(plot, column)| {
+            (column < tracks && distances[plot][column].is_some()).then_some(column)
+        })
+        .collect()
+}
+
+/// Minimum-cost assignment of every row to a distinct column, for a matrix
+/// with at least as many columns as rows (Kuhn-Munkres with potentials).
+fn hungarian(cost: &[Vec<f64>]) -> Vec<usize> {
+    let rows = cost.len();
+    let columns = cost.first().map_or(0, Vec::len);
+    // 1-based, with row and column 0 as the sentinel
+    let mut row_potential = vec![0.0; rows + 1];
+    let mut column_potential = vec![0.0; columns + 1];
+    let mut owner = vec![0usize; columns + 1];
+    let mut previous = vec![0usize; columns + 1];
+
+    for row in 1..=rows {
+        owner[0] = row;
+        let mut column = 0;
+        let mut slack = vec![f64::INFINITY; columns + 1];
+        let mut visited = vec![false; columns + 1];
+        loop {
+            visited[column] = true;
+            let current_row = owner[column];
+            let mut delta = f64::INFINITY;
+            let mut next = 0;
+            for candidate in 1..=columns {
+                if visited[candidate] {
+                    continue;
+                }
+                let reduced = cost[current_row - 1][candidate - 1]
+                    - row_potential[current_row]
+                    - column_potential[candidate];
+                if reduced < slack[candidate] {
+                    slack[candidate] = reduced;
+                    previous[candidate] = column;
+                }
+                if slack[candidate] < delta {
+                    delta = slack[candidate];
+                    next = candidate;
+                }
+            }
+            for candidate in 0..=columns {
+                if visited[candidate] {
+                    row_potential[owner[candidate]] += delta;
+                    column_potential[candidate] -= delta;
+                } else {
+                    slack[candidate] -= delta;
+                }
+            }
+            column = next;
+            if owner[column] == 0 {
+                break;
+            }
+        }
+        // Flip the augmenting path
+        while column != 0 {
+            let prior = previous[column];
+            owner[column] = owner[prior];
+            column = prior;
+        }
+    }
+
+    let mut assignment = vec![0; rows];
+    for column in 1..=columns {
+        if owner[column] != 0 {
+            assignment[owner[column] - 1] = column - 1;
+        }
+    }
+    assignment
+}
+
+/// Largest eigenvalue of the 2x2 block of `m` starting at `(start, start)`.
+fn largest_eigenvalue(m: &Matrix, start: usize) -> f64 {
+    let (a, b, c) = (
+        m[start][start],
+        m[start][start + 1],
+        m[start + 1][start + 1],
+    );
+    (a + c) / 2.0 + (((a - c) / 2.0).powi(2) + b * b).sqrt()
+}
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut product = [[0.0; 4]; 4];
+    for (row, product_row) in product.iter_mut().enumerate() {
+        for (col, value) in product_row.iter_mut().enumerate() {
+            *value = (0..4).map(|k| a[row][k] * b[k][col]).sum();
+        }
+    }
+    product
+}
+
+fn transpose(m: &Matrix) -> Matrix {
+    let mut transposed = [[0.0; 4]; 4];
+    for (row, m_row) in m.iter().enumerate() {
+        for (col, value) in m_row.iter().enumerate() {
+            transposed[col][row] = *value;
+        }
+    }
+    transposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::radar::{WorldObjects, angle_difference};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_track_converges_on_constant_velocity() {
+        let measurement = MeasurementModel::default();
+        let mut tracker = Tracker::default();
+        let mut rng = StdRng::seed_from_u64(9);
+        let mut object =
+            WorldObjects::from_polar(1, ObjectType::AirCraft, 45.0, 600.0).with_motion(200.0, 12.0);
+
+        let scan = 7.5;
+        let mut errors = Vec::new();
+        for i in 0..20 {
+            let time = i as f64 * scan;
+            let (angle, distance) = measurement.measure(
+                &mut rng,
+                object.angle_from((0.0, 0.0)),
+                object.distance_from((0.0, 0.0)),
+            );
+            tracker.predict(time);
+            tracker.process(&[Plot::at(angle, distance, time)], &measurement);
+            tracker.close_looks(|_| true);
+            errors.push(tracker.tracks[0].velocity_error());
+            object.advance(scan);
+        }
+
+        assert_eq!(tracker.tracks.len(), 1);
+        let track = &tracker.tracks[0];
+        assert_eq!(track.updates, 20);
+        assert_eq!(track.status, TrackStatus::Confirmed);
+        assert!(
+            (track.speed() - 12.0).abs() < 1.5,
+            "speed {}",
+            track.speed()
+        );
+        assert!(
+            angle_difference(track.course(), 200.0).abs() < 8.0,
+            "course {}",
+            track.course()
+        );
+        assert!(errors.last().unwrap() < &(errors[0] / 5.0));
+    }
+
+    #[test]
+    fn test_prediction_grows_uncertainty() {
+        let measurement = MeasurementModel::default();
+        let mut track = Track::new(1, &Plot::at(0.0, 100.0, 0.0), &measurement, 20.0);
+        track.state[2] = 3.0;
+        let before = track.position_error();
+
+        track.predict(10.0, 1.0);
+
+        assert!((track.state[0] - 130.0).abs() < 1e-9);
+        assert!(track.position_error() > before);
+    }
+
+    #[test]
+    fn test_track_lifecycle() {
+        let measurement = MeasurementModel::default();
+        let mut tracker = Tracker::default();
+        let mut events = Vec::new();
+        let mut scan = |tracker: &mut Tracker, plots: &[Plot]| {
+            tracker.process(plots, &measurement);
+            events.extend(tracker.close_looks(|_| true));
+        };
+
+        scan(&mut tracker, &[Plot::at(30.0, 400.0, 0.0)]);
+        assert_eq!(tracker.tracks[0].status, TrackStatus::Tentative);
+        // Second hit confirms; a plot far outside the gate starts another track
+        scan(
+            &mut tracker,
+            &[Plot::at(30.0, 402.0, 1.0), Plot::at(120.0, 400.0, 1.0)],
+        );
+        let summary: Vec<_> = tracker
+            .tracks
+            .iter()
+            .map(|track| (track.id, track.status))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(1, TrackStatus::Confirmed), (2, TrackStatus::Tentative)]
+        );
+
+        scan(&mut tracker, &[]);
+        assert_eq!(tracker.tracks[0].status, TrackStatus::Coasting);
+        // Track 2 can no longer reach 2 of 3 and goes without an event
+        scan(&mut tracker, &[]);
+        assert_eq!(tracker.tracks.len(), 1);
+        scan(&mut tracker, &[]);
+        assert!(tracker.tracks.is_empty());
+
+        assert_eq!(
+            events,
+            vec![
+                TrackEvent::Confirmed { id: 1 },
+                TrackEvent::Coasting { id: 1 },
+                TrackEvent::Lost { id: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pinned_track_past_drop_takes_no_plots() {
+        let measurement = MeasurementModel::default();
+        let mut tracker = Tracker::default();
+        for time in [0.0, 1.0] {
+            tracker.process(&[Plot::at(30.0, 400.0, time)], &measurement);
+            tracker.close_looks(|_| true);
+        }
+        tracker.tracks[0].pinned = true;
+        for _ in 0..tracker.drop_misses {
+            tracker.close_looks(|_| true);
+        }
+
+        // Minutes of coasting leave a gate wide enough to take anything
+        tracker.predict(600.0);
+        tracker.process(&[Plot::at(120.0, 400.0, 600.0)], &measurement);
+        assert_eq!(tracker.tracks.len(), 2);
+        assert_eq!(tracker.tracks[0].updates, 2);
+    }
+
+    #[test]
+    fn test_history_keeps_last_plots() {
+        let measurement = MeasurementModel::default();
+        let mut tracker = Tracker {
+            history_length: 3,
+            ..Tracker::default()
+        };
+        for i in 0..5 {
+            tracker.process(&[Plot::at(0.0, 100.0 + i as f64, i as f64)], &measurement);
+        }
+
+        let times: Vec<f64> = tracker.tracks[0].history.iter().map(|p| p.2).collect();
+        assert_eq!(times, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_approach_of_passing_track() {
+        let measurement = MeasurementModel::default();
+        // 100 east, 50 north, heading west at 5 per second
+        let mut track = Track::new(1, &Plot::at(0.0, 100.0, 0.0), &measurement, 20.0);
+        track.state = [100.0, 50.0, -5.0, 0.0];
+
+        let own_ship = OwnShip::default();
+        let approach = track.approach(&own_ship);
+        assert!((approach.cpa - 50.0).abs() < 1e-9);
+        assert!((approach.tcpa - 20.0).abs() < 1e-9);
+        assert_eq!(approach.to_string(), "CPA 50 in 0:20");
+
+        // Once past, the closest point lies behind it
+        track.state[0] = -100.0;
+        assert!(track.approach(&own_ship).tcpa < 0.0);
+    }
+
+    #[test]
+    fn test_global_assignment_beats_greedy() {
+        // Greedy takes the single closest pair (plot 0, track 0) and leaves
+        // plot 1 with a poor match; the global assignment crosses them over.
+        let distances = vec![vec![Some(1.0), Some(2.0)], vec![Some(1.5), Some(8.0)]];
+        assert_eq!(assign_nearest(&distances), vec![Some(0), Some(1)]);
+        assert_eq!(assign_global(&distances, 9.0), vec![Some(1), Some(0)]);
+
+        // Starting a new track is preferred over a match costing more than the gate
+        let distances = vec![vec![Some(1.0)], vec![Some(2.0)]];
+        assert_eq!(assign_global(&distances, 9.0), vec![Some(0), None]);
+    }
+}
@@ -1,14 +1,24 @@
+use crate::config::{Config, SpawnMix};
+use crate::contact_history::{ContactHistory, sparkline};
 use crate::fps_counter::FpsCounter;
-use crate::radar::RadarWidget;
+use crate::radar::{ObjectType, RadarWidget, SimEvent, normalize_angle};
+use crate::scenario::Scenario;
+use crate::tracker::{Track, TrackEvent, TrackStatus};
+use crate::zone::{Zone, ZoneKind, ZoneShape};
 use ratatui::backend::CrosstermBackend;
-use ratatui::crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::crossterm::event::{
+    DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+    EnableFocusChange, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
+    MouseButton, MouseEventKind,
+};
 use ratatui::crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
-use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
 use ratatui::prelude::Color;
 use ratatui::style::{Style, Stylize};
-use ratatui::text::{Line, Text};
-use ratatui::widgets::{Block, Borders, Paragraph};
-use ratatui::{Terminal, crossterm};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::{Frame, Terminal, crossterm};
+use std::collections::VecDeque;
 use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
@@ -20,7 +30,30 @@ pub enum Message {
     Quit,
     Tick,
     Render,
-    KeyPress(KeyCode),
+    KeyPress(KeyEvent),
+    /// Left mouse button pressed over this terminal cell.
+    Click {
+        column: u16,
+        row: u16,
+    },
+    /// Mouse pointer moved over this terminal cell.
+    Hover {
+        column: u16,
+        row: u16,
+    },
+    /// Mouse wheel turned over this terminal cell; magnify the radar view by
+    /// `factor` about it.
+    Zoom {
+        factor: f64,
+        column: u16,
+        row: u16,
+    },
+    /// The terminal changed size.
+    Resize,
+    /// The terminal gained (`true`) or lost (`false`) focus.
+    Focus(bool),
+    /// Text pasted into the terminal.
+    Paste(String),
 }
 
 #[derive(Clone, Debug)]
@@ -32,9 +65,105 @@ pub enum UpdateCommand {
 pub struct Model {
     pub fps_counter: FpsCounter,
     pub radar: RadarWidget,
-    pub last_spawn_time: Instant,
-    pub sweep_rate: f64,
+    pub last_spawn_time: f64,
     pub next_id: u32,
+    pub spawn_interval: f64,
+    pub spawn_mix: SpawnMix,
+    pub scenario: Option<Scenario>,
+    /// Index of the next scenario timeline entry to apply.
+    pub timeline_index: usize,
+    pub filter_open: bool,
+    /// The zone editor replaces the legend while open.
+    pub zones_open: bool,
+    pub selected_zone: usize,
+    /// Zone alarms not yet acknowledged, oldest first.
+    pub alarms: VecDeque<String>,
+    pub paused: bool,
+    pub time_scale: f64,
+    pub last_event: Option<String>,
+    /// Recent events, oldest first, shown beside the scope when there is room.
+    pub event_log: VecDeque<String>,
+    /// Where the scope was last drawn, for mapping mouse positions onto it.
+    pub radar_area: Rect,
+    /// Terminal cell under the mouse pointer.
+    pub hover: Option<(u16, u16)>,
+    /// Track number typed after `/`, while the find line is open.
+    pub track_search: Option<String>,
+    /// Whether the terminal has focus; rendering slows down without it.
+    pub focused: bool,
+    pub contact_history: ContactHistory,
+    /// The key list is shown over everything while open.
+    pub help_open: bool,
+}
+
+impl Model {
+    pub fn new(config: &Config, scenario: Option<Scenario>) -> Self {
+        let mut radar = config.build_radar();
+        Tui::seed_world(&mut radar, scenario.as_ref());
+        let next_id = Tui::first_spawn_id(scenario.as_ref());
+        Self {
+            fps_counter: FpsCounter::new(),
+            radar,
+            last_spawn_time: 0.0,
+            next_id,
+            spawn_interval: config.spawn_interval,
+            spawn_mix: config.spawn_mix.clone(),
+            scenario,
+            timeline_index: 0,
+            filter_open: false,
+            zones_open: false,
+            selected_zone: 0,
+            alarms: VecDeque::new(),
+            paused: false,
+            time_scale: 1.0,
+            last_event: None,
+            event_log: VecDeque::new(),
+            radar_area: Rect::default(),
+            hover: None,
+            track_search: None,
+            focused: true,
+            help_open: false,
+            contact_history: ContactHistory::new(
+                Tui::CONTACT_SAMPLE_INTERVAL,
+                Tui::CONTACT_SAMPLES,
+            ),
+        }
+    }
+
+    /// Time between frames at `frame_rate`, or at `UNFOCUSED_FRAME_RATE`
+    /// while the terminal is unfocused.
+    fn frame_duration(&self, frame_rate: f64) -> Duration {
+        let rate = if self.focused {
+            frame_rate
+        } else {
+            Tui::UNFOCUSED_FRAME_RATE
+        };
+        Duration::from_secs_f64(1.0 / rate)
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.hover = None;
+        }
+    }
+
+    /// Pasted text goes into the find line, if it is open, minus line breaks
+    /// and other control characters.
+    fn paste(&mut self, text: &str) {
+        if let Some(search) = &mut self.track_search {
+            search.extend(text.chars().filter(|c| !c.is_control()));
+        }
+    }
+
+    /// Queue a zone alarm for acknowledgement, dropping the oldest once
+    /// `MAX_ALARMS` are waiting.
+    fn raise_alarm(&mut self, message: String) {
+        if self.alarms.len() == Tui::MAX_ALARMS {
+            self.alarms.pop_front();
+        }
+        self.alarms.push_back(message);
+    }
 }
 
 pub struct Tui {
@@ -47,15 +176,46 @@ pub struct Tui {
 }
 
 impl Tui {
-    pub fn new(frame_rate: f64, tick_rate: f64) -> MyResult<Self> {
+    /// Build the TUI from resolved settings; with no seed a random one is picked
+    /// and shown in the System panel.
+    ///
+    /// A `scenario` replaces the default traffic with its own objects and timeline.
+    pub fn new(config: Config, scenario: Option<Scenario>) -> MyResult<Self> {
         let terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
         let (msg_tx, msg_rx) = mpsc::channel();
 
-        let sweep_rate = RadarWidget::DEGREES_PER_SECOND / 6.0;
+        Ok(Self {
+            terminal,
+            frame_rate: config.frame_rate,
+            tick_rate: config.tick_rate,
+            msg_tx,
+            msg_rx,
+            model: Model::new(&config, scenario),
+        })
+    }
 
-        let fade_duration = sweep_rate * 1.75;
+    /// Ids handed out by the periodic spawner start here, above the seeded objects.
+    const FIRST_SPAWN_ID: u32 = 1000;
 
-        let mut radar = RadarWidget::new(1000.0, fade_duration);
+    /// Keep random spawns clear of every id the scenario declares.
+    fn first_spawn_id(scenario: Option<&Scenario>) -> u32 {
+        scenario
+            .into_iter()
+            .flat_map(|s| {
+                let spawned = s.timeline.iter().filter_map(|e| e.spawn.as_ref());
+                s.objects
+                    .iter()
+                    .chain(spawned)
+                    .map(|o| o.id.saturating_add(1))
+            })
+            .fold(Self::FIRST_SPAWN_ID, u32::max)
+    }
+
+    fn seed_world(radar: &mut RadarWidget, scenario: Option<&Scenario>) {
+        if let Some(scenario) = scenario {
+            scenario.populate(radar);
+            return;
+        }
 
         radar.spawn_aircraft(1);
         radar.spawn_ship(100);
@@ -66,33 +226,182 @@ impl Tui {
 
         radar.spawn_aircraft(2);
         radar.spawn_ship(101);
+    }
 
-        Ok(Self {
-            terminal,
-            frame_rate,
-            tick_rate,
-            msg_tx,
-            msg_rx,
-            model: Model {
-                fps_counter: FpsCounter::new(),
-                radar,
-                last_spawn_time: Instant::now(),
-                sweep_rate,
-                next_id: 1000,
-            },
-        })
+    const MIN_TIME_SCALE: f64 = 0.25;
+    const MAX_TIME_SCALE: f64 = 16.0;
+    /// Alerts listed in the Contacts panel before the rest are summarized.
+    const MAX_LISTED_ALERTS: usize = 3;
+    /// Events kept for the event log.
+    const MAX_EVENT_LOG: usize = 100;
+    /// Unacknowledged zone alarms kept.
+    const MAX_ALARMS: usize = 100;
+    /// Narrowest spare column beside the scope worth giving to side panels.
+    const MIN_SIDE_PANEL_WIDTH: u16 = 24;
+    /// Rows of the selected-track panel, borders included.
+    const DETAIL_HEIGHT: u16 = 10;
+    /// Every key and what it does, for the help overlay.
+    const KEY_BINDINGS: [(&str, &str); 21] = [
+        ("Q / Esc", "Quit"),
+        ("SPACE", "Reset the run"),
+        ("P", "Pause"),
+        ("N", "Step once while paused"),
+        ("+ / -", "Faster / slower"),
+        ("R", "Next range scale"),
+        ("F", "Filter object types"),
+        ("Z", "Edit zones"),
+        ("A", "Acknowledge alarms"),
+        ("V", "Velocity leaders"),
+        ("T", "Trails"),
+        ("O", "Next orientation"),
+        ("M", "True / relative motion"),
+        ("Tab / arrows", "Select next / previous track"),
+        ("Esc", "Release the selected track"),
+        ("Click", "Select the track under the pointer"),
+        ("/", "Find a track by number"),
+        ("[ / ] / wheel", "Zoom out / in"),
+        ("Shift+arrows", "Pan"),
+        ("C / Home", "Center on selection / reset view"),
+        ("?", "This help"),
+    ];
+    /// Columns of the help overlay, wide enough for the longest binding.
+    const HELP_WIDTH: u16 = 54;
+    /// Rows of the contact statistics panel, borders included.
+    const STATISTICS_HEIGHT: u16 = 7;
+    /// Zoom factor for one key press or wheel notch.
+    const ZOOM_STEP: f64 = 1.25;
+    /// Frames per second drawn while the terminal is unfocused.
+    const UNFOCUSED_FRAME_RATE: f64 = 2.0;
+    /// Simulation seconds between contact history samples...
+    const CONTACT_SAMPLE_INTERVAL: f64 = 5.0;
+    /// ...and samples kept, three minutes' worth.
+    const CONTACT_SAMPLES: usize = 36;
+
+    /// Advance the simulation by one tick at `tick_rate`, scaled by `time_scale`.
+    ///
+    /// Fast-forward is split into sub-ticks no longer than a real tick so the sweep
+    /// still visits every bearing instead of jumping over targets.
+    fn advance(&mut self, time_scale: f64) {
+        let (sub_ticks, delta_time) = Self::sub_ticks(1.0 / self.tick_rate, time_scale);
+        for _ in 0..sub_ticks {
+            self.step_simulation(delta_time);
+        }
+    }
+
+    /// How many steps, and of how long, one tick of `base_dt` takes at `time_scale`.
+    fn sub_ticks(base_dt: f64, time_scale: f64) -> (u32, f64) {
+        let sub_ticks = time_scale.ceil().max(1.0) as u32;
+        (sub_ticks, base_dt * time_scale / sub_ticks as f64)
+    }
+
+    /// `time_scale` multiplied by `factor`, kept within the supported speeds.
+    fn scale_time(time_scale: f64, factor: f64) -> f64 {
+        (time_scale * factor).clamp(Self::MIN_TIME_SCALE, Self::MAX_TIME_SCALE)
+    }
+
+    fn step_simulation(&mut self, delta_time: f64) {
+        self.model.radar.update_world_objects(delta_time);
+        self.model.radar.update_sweep(delta_time);
+        let now = self.model.radar.sim_time();
+
+        if let Some(scenario) = &self.model.scenario {
+            while let Some(entry) = scenario.timeline.get(self.model.timeline_index)
+                && entry.time <= now
+            {
+                entry.apply(&mut self.model.radar);
+                self.model.timeline_index += 1;
+            }
+        }
+
+        for event in self.model.radar.drain_events() {
+            if let SimEvent::Track(TrackEvent::Confirmed { id }) = event
+                && self
+                    .model
+                    .radar
+                    .visible_tracks()
+                    .any(|track| track.id == id)
+            {
+                self.model.contact_history.record_new();
+            }
+            let message = format!("[{now:>7.1}s] {event}");
+            if matches!(
+                event,
+                SimEvent::ZoneEntered { .. } | SimEvent::ZoneExited { .. }
+            ) {
+                self.model.raise_alarm(message.clone());
+            }
+            if self.model.event_log.len() == Self::MAX_EVENT_LOG {
+                self.model.event_log.pop_front();
+            }
+            self.model.event_log.push_back(message.clone());
+            self.model.last_event = Some(message);
+        }
+
+        // Like the rest of the Contacts panel, hidden types are left out
+        let contacts = self
+            .model
+            .radar
+            .visible_tracks()
+            .filter(|track| track.status != TrackStatus::Tentative)
+            .count();
+        self.model.contact_history.sample(now, contacts);
+
+        let random_traffic = self
+            .model
+            .scenario
+            .as_ref()
+            .is_none_or(|s| s.random_traffic);
+
+        // Spawn diverse traffic
+        if random_traffic && now - self.model.last_spawn_time >= self.model.spawn_interval {
+            let id = self.model.next_id;
+            self.model.next_id += 1;
+
+            // Spawn different types with different frequencies
+            let weights = self.model.spawn_mix.weights();
+            self.model.radar.spawn_weighted(id, &weights);
+
+            self.model.last_spawn_time = now;
+        }
+    }
+
+    fn reset(&mut self) {
+        // Restart from the same seed so a reset replays the run
+        let seed = self.model.radar.seed();
+        self.model.radar.reseed(seed);
+        self.model.radar.clear();
+        Self::seed_world(&mut self.model.radar, self.model.scenario.as_ref());
+        self.model.next_id = Self::first_spawn_id(self.model.scenario.as_ref());
+        self.model.timeline_index = 0;
+        self.model.last_event = None;
+        self.model.event_log.clear();
+        self.model.alarms.clear();
+        self.model.last_spawn_time = self.model.radar.sim_time();
+        self.model.contact_history.clear(self.model.last_spawn_time);
     }
 
     fn enter(&self) -> MyResult<()> {
         crossterm::terminal::enable_raw_mode()?;
-        crossterm::execute!(std::io::stdout(), EnterAlternateScreen)?;
+        crossterm::execute!(
+            std::io::stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableFocusChange,
+            EnableBracketedPaste
+        )?;
         Ok(())
     }
 
     pub fn exit(&mut self) -> MyResult<()> {
         if crossterm::terminal::is_raw_mode_enabled()? {
             self.terminal.flush()?;
-            crossterm::execute!(std::io::stdout(), LeaveAlternateScreen)?;
+            crossterm::execute!(
+                std::io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                DisableFocusChange,
+                DisableBracketedPaste
+            )?;
             crossterm::terminal::disable_raw_mode()?;
             self.terminal.show_cursor()?;
             println!("Terminal exited.");
@@ -100,12 +409,43 @@ impl Tui {
         Ok(())
     }
 
+    /// The message for a terminal event, if the app reacts to it.
+    fn message_for(event: Event) -> Option<Message> {
+        let message = match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => Message::KeyPress(key),
+            Event::Mouse(mouse) => {
+                let (column, row) = (mouse.column, mouse.row);
+                match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left) => Message::Click { column, row },
+                    MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+                        Message::Hover { column, row }
+                    }
+                    MouseEventKind::ScrollUp => Message::Zoom {
+                        factor: Self::ZOOM_STEP,
+                        column,
+                        row,
+                    },
+                    MouseEventKind::ScrollDown => Message::Zoom {
+                        factor: 1.0 / Self::ZOOM_STEP,
+                        column,
+                        row,
+                    },
+                    _ => return None,
+                }
+            }
+            Event::Resize(_, _) => Message::Resize,
+            Event::FocusGained => Message::Focus(true),
+            Event::FocusLost => Message::Focus(false),
+            Event::Paste(text) => Message::Paste(text),
+            _ => return None,
+        };
+        Some(message)
+    }
+
     pub fn run(&mut self) -> MyResult<()> {
         self.enter()?;
 
         let tick_duration = Duration::from_secs_f64(1.0 / self.tick_rate);
-        let frame_duration = Duration::from_secs_f64(1.0 / self.frame_rate);
-
         let now = Instant::now();
         let mut last_tick = now;
         let mut last_frame = now;
@@ -115,12 +455,12 @@ impl Tui {
         thread::spawn(move || {
             // This thread blocks safely on input and sends key events to main thread
             loop {
-                if let Ok(Event::Key(key)) = crossterm::event::read() {
-                    if key.kind == KeyEventKind::Press {
-                        if input_tx.send(Message::KeyPress(key.code)).is_err() {
-                            break; // main thread exited
-                        }
-                    }
+                let Some(message) = crossterm::event::read().ok().and_then(Self::message_for)
+                else {
+                    continue;
+                };
+                if input_tx.send(message).is_err() {
+                    break; // main thread exited
                 }
             }
         });
@@ -129,12 +469,9 @@ impl Tui {
         loop {
             // Handle incoming messages (non-blocking)
             while let Ok(msg) = self.msg_rx.try_recv() {
-                match self.update(&msg)? {
-                    UpdateCommand::Quit => {
-                        self.exit()?;
-                        return Ok(());
-                    }
-                    _ => {}
+                if let UpdateCommand::Quit = self.update(&msg)? {
+                    self.exit()?;
+                    return Ok(());
                 }
             }
 
@@ -146,10 +483,15 @@ impl Tui {
                 last_tick += tick_duration;
             }
 
-            // Render frame
+            // Render frame, only at a trickle while the terminal is unfocused
+            let frame_duration = self.model.frame_duration(self.frame_rate);
             if now >= last_frame + frame_duration {
                 self.update(&Message::Render)?;
                 last_frame += frame_duration;
+                if now >= last_frame + frame_duration {
+                    // Fell behind, e.g. on regaining focus; don't rush to catch up
+                    last_frame = now;
+                }
             }
             // sleep until next event, yield to CPU
             let next_tick = last_tick + tick_duration;
@@ -166,35 +508,119 @@ impl Tui {
             Message::Quit => {
                 return Ok(UpdateCommand::None);
             }
-            Message::KeyPress(key) => match key {
+            Message::KeyPress(key) if self.model.help_open => {
+                if matches!(key.code, KeyCode::Esc | KeyCode::Char('?')) {
+                    self.model.help_open = false;
+                }
+            }
+            Message::KeyPress(key) if self.model.track_search.is_some() => {
+                self.edit_track_search(key.code);
+            }
+            Message::KeyPress(key) if self.model.filter_open => match key.code {
+                KeyCode::Esc | KeyCode::Char('f') | KeyCode::Char('F') => {
+                    self.model.filter_open = false;
+                }
+                KeyCode::Char(c) => {
+                    // 1-6 toggle the object types in legend order
+                    if let Some(object_type) = c
+                        .to_digit(10)
+                        .and_then(|d| (d as usize).checked_sub(1))
+                        .and_then(|i| ObjectType::ALL.get(i))
+                    {
+                        self.model.radar.toggle_type_filter(*object_type);
+                    }
+                }
+                _ => {}
+            },
+            Message::KeyPress(key) if self.model.zones_open => self.edit_zones(key.code),
+            Message::KeyPress(key) => match key.code {
+                KeyCode::Esc if self.model.radar.selected().is_some() => {
+                    self.model.radar.select(None);
+                }
                 KeyCode::Esc | KeyCode::Char('q') => {
                     return Ok(UpdateCommand::Quit);
                 }
+                KeyCode::Char(' ') => self.reset(),
+                KeyCode::Char('r') | KeyCode::Char('R') => self.model.radar.cycle_range(),
+                KeyCode::Char('f') | KeyCode::Char('F') => self.model.filter_open = true,
+                KeyCode::Char('z') | KeyCode::Char('Z') => self.model.zones_open = true,
+                KeyCode::Char('a') | KeyCode::Char('A') => self.model.alarms.clear(),
+                KeyCode::Char('/') => self.model.track_search = Some(String::new()),
+                KeyCode::Char('?') => self.model.help_open = true,
+                KeyCode::Char('v') | KeyCode::Char('V') => {
+                    self.model.radar.show_leaders = !self.model.radar.show_leaders;
+                }
+                KeyCode::Char('t') | KeyCode::Char('T') => {
+                    self.model.radar.show_trails = !self.model.radar.show_trails;
+                }
+                KeyCode::Char('o') | KeyCode::Char('O') => {
+                    let radar = &mut self.model.radar;
+                    radar.set_orientation(radar.orientation().next());
+                }
+                KeyCode::Char('m') | KeyCode::Char('M') => {
+                    let radar = &mut self.model.radar;
+                    radar.set_true_motion(!radar.is_true_motion());
+                }
+                KeyCode::Char(']') => self.model.radar.zoom_by(Self::ZOOM_STEP),
+                KeyCode::Char('[') => self.model.radar.zoom_by(1.0 / Self::ZOOM_STEP),
+                // Shift+arrows pan; plain arrows and Tab step through contacts
+                KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                    self.model.radar.pan(-1.0, 0.0);
+                }
+                KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                    self.model.radar.pan(1.0, 0.0);
+                }
+                KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                    self.model.radar.pan(0.0, 1.0);
+                }
+                KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                    self.model.radar.pan(0.0, -1.0);
+                }
+                KeyCode::Tab | KeyCode::Down | KeyCode::Right => {
+                    self.model.radar.cycle_selection(1);
+                }
+                KeyCode::BackTab | KeyCode::Up | KeyCode::Left => {
+                    self.model.radar.cycle_selection(-1);
+                }
+                KeyCode::Char('c') | KeyCode::Char('C') => self.model.radar.center_on_selected(),
+                KeyCode::Home => self.model.radar.reset_view(),
+                KeyCode::Char('p') | KeyCode::Char('P') => self.model.paused = !self.model.paused,
+                KeyCode::Char('n') | KeyCode::Char('N') if self.model.paused => self.advance(1.0),
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    self.model.time_scale = Self::scale_time(self.model.time_scale, 2.0);
+                }
+                KeyCode::Char('-') => {
+                    self.model.time_scale = Self::scale_time(self.model.time_scale, 0.5);
+                }
                 _ => {}
             },
+            Message::Click { column, row } => {
+                let area = self.model.radar_area;
+                if area.contains(Position::new(*column, *row)) {
+                    let id = self.model.radar.track_at(area, *column, *row);
+                    self.model.radar.select(id);
+                }
+            }
+            Message::Hover { column, row } => self.model.hover = Some((*column, *row)),
+            Message::Zoom {
+                factor,
+                column,
+                row,
+            } => {
+                self.model
+                    .radar
+                    .zoom_at(*factor, self.model.radar_area, *column, *row);
+            }
+            Message::Resize => {
+                // Lay out for the new size now rather than at the next frame
+                self.model.hover = None;
+                self.view()?;
+            }
+            Message::Focus(focused) => self.model.set_focus(*focused),
+            Message::Paste(text) => self.model.paste(text),
             Message::Tick => {
-                let delta_time = 1.0 / self.tick_rate;
-                let now = Instant::now();
-                self.model.radar.update_world_objects(delta_time);
-                self.model.radar.update_sweep(delta_time);
-
-                // Spawn diverse traffic
-                if now.duration_since(self.model.last_spawn_time).as_secs() >= 5 {
-                    let id = self.model.next_id;
-                    self.model.next_id += 1;
-
-                    // Spawn different types with different frequencies
-                    match id % 10 {
-                        0..=3 => self.model.radar.spawn_aircraft(id), // 40% aircraft
-                        4..=5 => self.model.radar.spawn_ship(id),     // 20% ships
-                        6 => self.model.radar.spawn_unknown(id),      // 10% unknown
-                        7 => self.model.radar.spawn_hostile(id),      // 10% hostile
-                        8 => self.model.radar.spawn_generic(id),      // 10% generic
-                        9 => self.model.radar.spawn_weather(id),      // 10% weather
-                        _ => self.model.radar.spawn_random_object(id),
-                    }
-
-                    self.model.last_spawn_time = now;
+                if !self.model.paused {
+                    self.advance(self.model.time_scale);
                 }
             }
             Message::Render => {
@@ -205,18 +631,361 @@ impl Tui {
         Ok(UpdateCommand::None)
     }
 
+    /// Keys while the find line is open: type a track number, Enter selects it.
+    fn edit_track_search(&mut self, key: KeyCode) {
+        let Some(search) = &mut self.model.track_search else {
+            return;
+        };
+        match key {
+            KeyCode::Esc => self.model.track_search = None,
+            KeyCode::Backspace => {
+                search.pop();
+            }
+            KeyCode::Char(c) => search.push(c),
+            KeyCode::Enter => {
+                let wanted = search
+                    .trim()
+                    .trim_start_matches(['T', 't'])
+                    .parse::<u32>()
+                    .ok();
+                // Only what Tab and clicks can pick; tentative tracks may vanish
+                let found = wanted.filter(|&id| {
+                    self.model
+                        .radar
+                        .visible_tracks()
+                        .any(|track| track.id == id && track.status != TrackStatus::Tentative)
+                });
+                match found {
+                    Some(id) => {
+                        self.model.radar.select(Some(id));
+                        self.model.radar.center_on_selected();
+                    }
+                    None => self.model.last_event = Some(format!("No track {search}")),
+                }
+                self.model.track_search = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Keys while the zone editor is open; edits apply to the selected zone.
+    fn edit_zones(&mut self, key: KeyCode) {
+        let zones = &mut self.model.radar.zones;
+        let selected = self.model.selected_zone;
+        match key {
+            KeyCode::Esc | KeyCode::Char('z') | KeyCode::Char('Z') => {
+                self.model.zones_open = false;
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                // A sector ahead of the sweep, sized to the display range
+                let bearing = self.model.radar.sweep_angle;
+                let range = self.model.radar.max_range;
+                zones.push(Zone::new(
+                    format!("Zone {}", zones.len() + 1),
+                    ZoneKind::Guard,
+                    ZoneShape::Sector {
+                        min_range: range * 0.25,
+                        max_range: range * 0.5,
+                        start_bearing: normalize_angle(bearing + 5.0),
+                        end_bearing: normalize_angle(bearing + 35.0),
+                    },
+                ));
+                self.model.selected_zone = zones.len() - 1;
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                if let Some(i) = c.to_digit(10).and_then(|d| (d as usize).checked_sub(1))
+                    && i < zones.len()
+                {
+                    self.model.selected_zone = i;
+                }
+            }
+            _ => {
+                let Some(zone) = zones.get_mut(selected) else {
+                    return;
+                };
+                match key {
+                    KeyCode::Char('e') | KeyCode::Char('E') => zone.enabled = !zone.enabled,
+                    KeyCode::Char('k') | KeyCode::Char('K') => {
+                        zone.kind = match zone.kind {
+                            ZoneKind::Guard => ZoneKind::Exclusion,
+                            ZoneKind::Exclusion => ZoneKind::Guard,
+                        };
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => zone.shape.scale(1.1),
+                    KeyCode::Char('-') => zone.shape.scale(1.0 / 1.1),
+                    KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Delete => {
+                        zones.remove(selected);
+                        self.model.selected_zone = selected.min(zones.len().saturating_sub(1));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Read-out of the selected track, if any.
+    fn selected_details(&self) -> Option<Vec<Line<'static>>> {
+        let radar = &self.model.radar;
+        let track = radar.selected_track()?;
+        let origin = radar.own_ship.position();
+        let bearings = radar.bearing_convention;
+        let now = radar.sim_time();
+        Some(vec![
+            Line::styled(
+                format!("T{} {}", track.id, track.object_type.name()),
+                Style::default().fg(track.object_type.color()).bold(),
+            ),
+            Line::from(format!("Status: {}", track.status.name())),
+            Line::from(format!("Range: {:.1} nm", track.distance_from(origin))),
+            Line::from(format!(
+                "Bearing: {}",
+                bearings.format(track.angle_from(origin))
+            )),
+            Line::from(format!(
+                "Course: {}  Speed: {:.1}/s",
+                bearings.format(track.course()),
+                track.speed()
+            )),
+            Line::from(format!("Age: {:.0}s", now - track.start_time)),
+            Line::from(format!(
+                "Last hit: {:.1}s ago",
+                now - track.last_update_time
+            )),
+            Line::from(format!("Visibility: {:.0}%", track.visibility * 100.0)),
+        ])
+    }
+
+    /// Every key binding, centered over the whole screen.
+    fn render_help(f: &mut Frame) {
+        let lines: Vec<Line> = Self::KEY_BINDINGS
+            .iter()
+            .map(|(keys, action)| {
+                Line::from(vec![
+                    Span::styled(format!("{keys:<16}"), Style::default().fg(Color::Yellow)),
+                    Span::raw(*action),
+                ])
+            })
+            .collect();
+        let area = f.area();
+        let width = Self::HELP_WIDTH.min(area.width);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let popup = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        f.render_widget(Clear, popup);
+        f.render_widget(
+            Paragraph::new(Text::from(lines)).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Keys (? or Esc to close)"),
+            ),
+            popup,
+        );
+    }
+
+    /// Per-type counts, nearest and farthest contact and the history
+    /// sparklines, for lines `width` columns wide.
+    fn contact_statistics(model: &Model, width: usize) -> Vec<Line<'static>> {
+        let radar = &model.radar;
+        let contacts: Vec<&Track> = radar
+            .visible_tracks()
+            .filter(|track| track.status != TrackStatus::Tentative)
+            .collect();
+
+        // Symbol and count of each type being tracked
+        let type_counts = ObjectType::ALL.iter().filter_map(|&object_type| {
+            let n = contacts
+                .iter()
+                .filter(|track| track.object_type == object_type)
+                .count();
+            (n > 0).then(|| {
+                Span::styled(
+                    format!("{}{n} ", object_type.symbol()),
+                    Style::default().fg(object_type.color()),
+                )
+            })
+        });
+
+        // Nearest and farthest contact from own ship
+        let own = radar.own_ship.position();
+        let by_range =
+            |a: &&&Track, b: &&&Track| a.distance_from(own).total_cmp(&b.distance_from(own));
+        let describe = |label: &str, track: Option<&&Track>| match track {
+            Some(track) => Line::from(format!(
+                "{label}: T{} {:.1} nm {}",
+                track.id,
+                track.distance_from(own),
+                radar.bearing_convention.format(track.angle_from(own))
+            )),
+            None => Line::from(format!("{label}: --")),
+        };
+
+        // Contact count and new contacts per sample over the history
+        let history = &model.contact_history;
+        let spark_width = width.saturating_sub(6);
+        vec![
+            Line::from_iter(type_counts),
+            describe("Nearest", contacts.iter().min_by(by_range)),
+            describe("Farthest", contacts.iter().max_by(by_range)),
+            Line::from(vec![
+                Span::raw("Count "),
+                Span::styled(
+                    sparkline(&history.counts, spark_width),
+                    Style::default().fg(Color::Green),
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("New   "),
+                Span::styled(
+                    sparkline(&history.new_contacts, spark_width),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]),
+        ]
+    }
+
     fn view(&mut self) -> MyResult<()> {
+        let details = self.selected_details();
+        let details_panel = details.as_ref().map(|lines| {
+            Paragraph::new(Text::from(lines.clone())).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Selected (Esc to release)"),
+            )
+        });
+        let mut scope_area = Rect::default();
         self.terminal.draw(|f| {
-            let main_chunks = Layout::default()
+            let mut main_chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
                     Constraint::Percentage(80), // Radar
+                    Constraint::Length(1),      // Event line
                     Constraint::Percentage(20), // Controls
                 ])
                 .split(f.area());
 
-            // Radar display (80%)
-            f.render_widget(&self.model.radar, main_chunks[0]);
+            // Radar display (80%), with any width the round scope cannot use
+            // going to the side panels
+            let (scope_width, scope_height) = self.model.radar.scope_size(main_chunks[0]);
+            let spare = main_chunks[0].width - scope_width;
+            let side_column = spare >= Self::MIN_SIDE_PANEL_WIDTH;
+            if !side_column && scope_height < main_chunks[0].height {
+                // A narrow terminal leaves rows under the scope; give them to the panels
+                main_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(scope_height),
+                        Constraint::Length(1),
+                        Constraint::Min(0),
+                    ])
+                    .split(f.area());
+            }
+            scope_area = if side_column {
+                let radar_chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Length(scope_width), Constraint::Min(0)])
+                    .split(main_chunks[0]);
+
+                // The selected track's details, then the contact statistics,
+                // sit above the event log
+                let detail_height = if details_panel.is_some() {
+                    Self::DETAIL_HEIGHT
+                } else {
+                    0
+                };
+                let side_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(detail_height),
+                        Constraint::Length(Self::STATISTICS_HEIGHT),
+                        Constraint::Min(0),
+                    ])
+                    .split(radar_chunks[1]);
+                if let Some(panel) = &details_panel {
+                    f.render_widget(panel, side_chunks[0]);
+                }
+                let spark_width = side_chunks[1].width.saturating_sub(2) as usize;
+                let statistics = Paragraph::new(Text::from(Self::contact_statistics(
+                    &self.model,
+                    spark_width,
+                )))
+                .block(Block::default().borders(Borders::ALL).title(format!(
+                    "Statistics (last {:.0} min)",
+                    self.model.contact_history.span() / 60.0
+                )));
+                f.render_widget(statistics, side_chunks[1]);
+                let log_area = side_chunks[2];
+
+                // Newest events at the bottom, as many as fit
+                let rows = log_area.height.saturating_sub(2) as usize;
+                let skip = self.model.event_log.len().saturating_sub(rows);
+                let log_lines = self
+                    .model
+                    .event_log
+                    .iter()
+                    .skip(skip)
+                    .map(|event| Line::from(event.as_str()))
+                    .collect::<Vec<_>>();
+                let log = Paragraph::new(Text::from(log_lines))
+                    .block(Block::default().borders(Borders::ALL).title("Events"));
+                f.render_widget(log, log_area);
+                radar_chunks[0]
+            } else {
+                main_chunks[0]
+            };
+            f.render_widget(&self.model.radar, scope_area);
+
+            // Range and bearing from own ship to the point under the pointer
+            let radar = &self.model.radar;
+            if let Some((column, row)) = self.model.hover
+                && let Some((x, y)) = radar.screen_to_world(scope_area, column, row)
+            {
+                let (own_x, own_y) = radar.own_ship.position();
+                let bearing = normalize_angle((y - own_y).atan2(x - own_x).to_degrees());
+                let tip = format!(
+                    " {:.1} nm {} ",
+                    (x - own_x).hypot(y - own_y),
+                    radar.bearing_convention.format(bearing)
+                );
+                // Beside the pointer, above it unless on the top row
+                let width = (tip.chars().count() as u16).min(scope_area.width);
+                let tip_x = (column + 2).min(scope_area.right() - width);
+                let tip_y = if row > scope_area.y { row - 1 } else { row + 1 };
+                f.render_widget(
+                    Paragraph::new(tip)
+                        .style(Style::default().fg(Color::White).bg(Color::DarkGray)),
+                    Rect::new(tip_x, tip_y, width, 1).intersection(scope_area),
+                );
+            }
+
+            // The find line, then unacknowledged zone alarms, take over the event line
+            if let Some(search) = &self.model.track_search {
+                f.render_widget(
+                    Paragraph::new(format!(
+                        "Find track: {search}_ (Enter to select, Esc to cancel)"
+                    ))
+                    .style(Style::default().fg(Color::Yellow)),
+                    main_chunks[1],
+                );
+            } else if let Some(latest) = self.model.alarms.back() {
+                let more = match self.model.alarms.len() {
+                    1 => String::new(),
+                    n => format!(" (+{} more)", n - 1),
+                };
+                f.render_widget(
+                    Paragraph::new(format!("ALARM {latest}{more} - A to acknowledge"))
+                        .style(Style::default().fg(Color::White).bg(Color::Red).bold()),
+                    main_chunks[1],
+                );
+            } else if let Some(event) = &self.model.last_event {
+                f.render_widget(
+                    Paragraph::new(event.as_str()).style(Style::default().fg(Color::Gray)),
+                    main_chunks[1],
+                );
+            }
 
             // Control panel (20%)split horizontally into 4 sections
             let control_chunks = Layout::default()
@@ -227,50 +996,190 @@ impl Tui {
                     Constraint::Percentage(25), // Legend
                     Constraint::Percentage(25), // Controls
                 ])
-                .split(main_chunks[1]);
+                .split(main_chunks[2]);
 
             // System info panel (cleaner without legend)
-            let system_text = Text::from(vec![
+            let mut system_lines = Vec::new();
+            if self.model.paused {
+                system_lines.push(Line::styled(
+                    "PAUSED (N - step)",
+                    Style::default().fg(Color::Black).bg(Color::Yellow).bold(),
+                ));
+            }
+            system_lines.extend([
                 Line::from(format!("FPS: {}", self.model.fps_counter.fps)),
-                Line::from(format!("Range: {} nm", self.model.radar.max_range)),
-                Line::from(format!("Sweep Rate: {:.1} RPM", self.model.sweep_rate)),
+                Line::from(format!("Time: {}x", self.model.time_scale)),
+                Line::from(format!(
+                    "Range: {} nm (x{:.1})",
+                    self.model.radar.max_range,
+                    self.model.radar.zoom()
+                )),
+                Line::from(format!(
+                    "Sweep Rate: {:.1} RPM",
+                    self.model.radar.rotation_rate / 6.0
+                )),
+                Line::from(format!("Seed: {}", self.model.radar.seed())),
+                Line::from(format!(
+                    "Display: {} {}",
+                    self.model.radar.orientation().name(),
+                    if self.model.radar.is_true_motion() {
+                        "TM"
+                    } else {
+                        "RM"
+                    }
+                )),
+                Line::from(format!(
+                    "Own ship: {} {:.1}/s",
+                    self.model
+                        .radar
+                        .bearing_convention
+                        .format(self.model.radar.own_ship.heading),
+                    self.model.radar.own_ship.speed
+                )),
                 Line::from(""),
                 Line::from("System Status:"),
                 Line::styled("● Online", Style::default().fg(Color::Green)),
                 Line::styled("● Tracking", Style::default().fg(Color::Green)),
             ]);
 
-            let system_info = Paragraph::new(system_text)
+            let system_info = Paragraph::new(Text::from(system_lines))
                 .block(Block::default().borders(Borders::ALL).title("System"));
             f.render_widget(system_info, control_chunks[0]);
 
             // Target info panel
-            let target_info = Paragraph::new(format!(
-                "Contacts: {}\n\nAlerts: 0\n\nNearest:\n--:-- nm\n\nFarthest:\n--:-- nm",
-                self.model.radar.detected_contacts.len(),
-            ))
-            .block(Block::default().borders(Borders::ALL).title("Contacts"));
+            let count = |status| {
+                self.model
+                    .radar
+                    .visible_tracks()
+                    .filter(|track| track.status == status)
+                    .count()
+            };
+            let coasting = count(TrackStatus::Coasting);
+            let alerts = self.model.radar.alerts();
+            let mut target_lines = vec![
+                Line::from(format!(
+                    "Tracks: {} ({coasting} coasting)",
+                    count(TrackStatus::Confirmed) + coasting
+                )),
+                Line::from(format!("Tentative: {}", count(TrackStatus::Tentative))),
+            ];
+            if alerts.is_empty() {
+                target_lines.push(Line::from("Alerts: 0"));
+            } else {
+                target_lines.push(Line::styled(
+                    format!("Alerts: {}", alerts.len()),
+                    Style::default().fg(Color::White).bg(Color::Red).bold(),
+                ));
+            }
+            target_lines.extend(alerts.iter().take(Self::MAX_LISTED_ALERTS).map(
+                |(track, approach)| {
+                    Line::styled(
+                        format!("T{} {approach}", track.id),
+                        Style::default().fg(Color::Red),
+                    )
+                },
+            ));
+            if alerts.len() > Self::MAX_LISTED_ALERTS {
+                target_lines.push(Line::from(format!(
+                    "+{} more",
+                    alerts.len() - Self::MAX_LISTED_ALERTS
+                )));
+            }
+            // Without a side column the statistics come here, after the alerts,
+            // in whatever rows are left
+            if !side_column {
+                let spark_width = control_chunks[1].width.saturating_sub(2) as usize;
+                target_lines.extend(Self::contact_statistics(&self.model, spark_width));
+            }
+            let target_info = Paragraph::new(Text::from(target_lines))
+                .block(Block::default().borders(Borders::ALL).title("Contacts"));
             f.render_widget(target_info, control_chunks[1]);
 
-            // Legend panel
-            let legend_text = Text::from(vec![
-                Line::styled("^ Aircraft", Style::default().fg(Color::Cyan).bold()),
-                Line::styled("▢ Ship", Style::default().fg(Color::Green).bold()),
-                Line::styled("? Unknown", Style::default().fg(Color::Yellow).bold()),
-                Line::styled("X Hostile", Style::default().fg(Color::Red).bold()),
-                Line::styled("+ Generic", Style::default().fg(Color::White).bold()),
-                Line::styled("* Weather", Style::default().fg(Color::Magenta).bold()),
-            ]);
+            // Legend panel, doubles as the type filter while it is open
+            let legend_lines = ObjectType::ALL
+                .iter()
+                .enumerate()
+                .map(|(i, object_type)| {
+                    let label = format!("{} {}", object_type.symbol(), object_type.name());
+                    if self.model.filter_open {
+                        let mark = if self.model.radar.is_type_visible(*object_type) {
+                            "x"
+                        } else {
+                            " "
+                        };
+                        Line::styled(
+                            format!("{} [{mark}] {label}", i + 1),
+                            Style::default().fg(object_type.color()).bold(),
+                        )
+                    } else if self.model.radar.is_type_visible(*object_type) {
+                        Line::styled(label, Style::default().fg(object_type.color()).bold())
+                    } else {
+                        Line::styled(label, Style::default().fg(Color::DarkGray))
+                    }
+                })
+                .collect::<Vec<_>>();
 
-            let legend = Paragraph::new(legend_text)
-                .block(Block::default().borders(Borders::ALL).title("Legend"));
-            f.render_widget(legend, control_chunks[2]);
+            let legend_title = if self.model.filter_open {
+                "Filter (1-6, F to close)"
+            } else {
+                "Legend"
+            };
+            let legend = Paragraph::new(Text::from(legend_lines))
+                .block(Block::default().borders(Borders::ALL).title(legend_title));
 
-            // Controls panel
-            let controls = Paragraph::new("Q - Quit\nSPACE - Reset\nR - Range\nF - Filter\n")
-                .block(Block::default().borders(Borders::ALL).title("Controls"));
+            if self.model.zones_open {
+                let zone_lines = self
+                    .model
+                    .radar
+                    .zones
+                    .iter()
+                    .enumerate()
+                    .map(|(i, zone)| {
+                        let mark = if zone.enabled { "x" } else { " " };
+                        let color = match zone.kind {
+                            ZoneKind::Guard => Color::Blue,
+                            ZoneKind::Exclusion => Color::Gray,
+                        };
+                        let mut style = Style::default().fg(color);
+                        if i == self.model.selected_zone {
+                            style = style.reversed();
+                        }
+                        Line::styled(
+                            format!("{} [{mark}] {} {}", i + 1, zone.kind.name(), zone.name),
+                            style,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let editor = Paragraph::new(Text::from(zone_lines)).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Zones (1-9 N E K +/- D, Z to close)"),
+                );
+                f.render_widget(editor, control_chunks[2]);
+            } else if let Some(panel) = details_panel.as_ref().filter(|_| !side_column) {
+                // Without a side column the details take the legend's place
+                f.render_widget(panel, control_chunks[2]);
+            } else {
+                f.render_widget(legend, control_chunks[2]);
+            }
+
+            // Controls panel, the most used keys first; ? lists the rest
+            let controls = Paragraph::new(Text::from(vec![
+                Line::styled("? - All keys", Style::default().fg(Color::Yellow)),
+                Line::from("Q - Quit  P - Pause"),
+                Line::from("SPACE - Reset"),
+                Line::from("F - Filter  Z - Zones"),
+                Line::from("A - Ack alarms"),
+                Line::from("Tab - Select"),
+            ]))
+            .block(Block::default().borders(Borders::ALL).title("Controls"));
             f.render_widget(controls, control_chunks[3]);
+
+            if self.model.help_open {
+                Self::render_help(f);
+            }
         })?;
+        self.model.radar_area = scope_area;
 
         Ok(())
     }
@@ -281,3 +1190,101 @@ impl Drop for Tui {
         let _ = self.exit();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Settings;
+    use ratatui::backend::TestBackend;
+
+    #[test]
+    fn test_time_scale_sub_ticks() {
+        let base_dt = 0.05;
+        assert_eq!(Tui::sub_ticks(base_dt, 0.25), (1, base_dt / 4.0));
+        assert_eq!(Tui::sub_ticks(base_dt, 1.0), (1, base_dt));
+        assert_eq!(Tui::sub_ticks(base_dt, 16.0), (16, base_dt));
+
+        assert_eq!(Tui::scale_time(16.0, 2.0), Tui::MAX_TIME_SCALE);
+        assert_eq!(Tui::scale_time(0.25, 0.5), Tui::MIN_TIME_SCALE);
+        assert_eq!(Tui::scale_time(1.0, 2.0), 2.0);
+    }
+
+    fn model() -> Model {
+        let config = Config::resolve(Settings::default()).unwrap();
+        Model::new(&config, None)
+    }
+
+    #[test]
+    fn test_alarms_keep_the_newest() {
+        let mut model = model();
+        for i in 0..Tui::MAX_ALARMS + 5 {
+            model.raise_alarm(format!("alarm {i}"));
+        }
+        assert_eq!(model.alarms.len(), Tui::MAX_ALARMS);
+        assert_eq!(model.alarms.front().unwrap(), "alarm 5");
+    }
+
+    #[test]
+    fn test_terminal_events_forwarded() {
+        assert!(matches!(
+            Tui::message_for(Event::Resize(80, 24)),
+            Some(Message::Resize)
+        ));
+        assert!(matches!(
+            Tui::message_for(Event::FocusLost),
+            Some(Message::Focus(false))
+        ));
+        assert!(matches!(
+            Tui::message_for(Event::FocusGained),
+            Some(Message::Focus(true))
+        ));
+        assert!(matches!(
+            Tui::message_for(Event::Paste("42".to_string())),
+            Some(Message::Paste(text)) if text == "42"
+        ));
+    }
+
+    #[test]
+    fn test_unfocused_frames_throttle() {
+        let mut model = model();
+        model.hover = Some((3, 4));
+        assert_eq!(
+            model.frame_duration(30.0),
+            Duration::from_secs_f64(1.0 / 30.0)
+        );
+        model.set_focus(false);
+        assert_eq!(model.frame_duration(30.0), Duration::from_millis(500));
+        assert_eq!(model.hover, None);
+        model.set_focus(true);
+        assert_eq!(
+            model.frame_duration(30.0),
+            Duration::from_secs_f64(1.0 / 30.0)
+        );
+    }
+
+    #[test]
+    fn test_paste_goes_to_open_find_line() {
+        let mut model = model();
+        model.paste("12");
+        assert_eq!(model.track_search, None);
+        model.track_search = Some("T".to_string());
+        model.paste("12\r\n");
+        assert_eq!(model.track_search.as_deref(), Some("T12"));
+    }
+
+    #[test]
+    fn test_help_fits_small_terminal() {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(Tui::render_help).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        for (_, action) in Tui::KEY_BINDINGS {
+            assert!(screen.contains(action), "{action} not shown");
+        }
+    }
+}
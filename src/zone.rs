@@ -0,0 +1,314 @@
+use crate::radar::{ObjectType, normalize_angle};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+
+/// What a zone does with the tracks inside it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ZoneKind {
+    /// Raise an alarm when a track enters or leaves.
+    #[default]
+    Guard,
+    /// Drop plots inside so nothing is tracked there, e.g. over known clutter.
+    Exclusion,
+}
+
+impl ZoneKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ZoneKind::Guard => "Guard",
+            ZoneKind::Exclusion => "Exclusion",
+        }
+    }
+}
+
+/// Area covered by a zone, in radar-relative range units and bearings in degrees.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZoneShape {
+    /// Annulus between two ranges, from `start_bearing` counter-clockwise to `end_bearing`.
+    Sector {
+        min_range: f64,
+        max_range: f64,
+        start_bearing: f64,
+        end_bearing: f64,
+    },
+    /// Closed polygon through `(x, y)` corners.
+    Polygon(Vec<(f64, f64)>),
+    /// Everything within `radius` of the radar.
+    Ring { radius: f64 },
+}
+
+impl ZoneShape {
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        match self {
+            ZoneShape::Sector {
+                min_range,
+                max_range,
+                start_bearing,
+                end_bearing,
+            } => {
+                let range = x.hypot(y);
+                if range < *min_range || range > *max_range {
+                    return false;
+                }
+                let bearing = normalize_angle(y.atan2(x).to_degrees());
+                let span = normalize_angle(end_bearing - start_bearing);
+                // Equal bearings cover the whole annulus
+                span == 0.0 || normalize_angle(bearing - start_bearing) <= span
+            }
+            ZoneShape::Polygon(points) => {
+                // Even-odd rule: count edges crossed by a ray toward +x
+                let mut inside = false;
+                for (i, &(x1, y1)) in points.iter().enumerate() {
+                    let (x2, y2) = points[(i + 1) % points.len()];
+                    if (y1 > y) != (y2 > y) && x < x1 + (y - y1) * (x2 - x1) / (y2 - y1) {
+                        inside = !inside;
+                    }
+                }
+                inside
+            }
+            ZoneShape::Ring { radius } => x.hypot(y) <= *radius,
+        }
+    }
+
+    /// Grow or shrink the shape about the radar by `factor`.
+    pub fn scale(&mut self, factor: f64) {
+        match self {
+            ZoneShape::Sector {
+                min_range,
+                max_range,
+                ..
+            } => {
+                *min_range *= factor;
+                *max_range *= factor;
+            }
+            ZoneShape::Polygon(points) => {
+                for (x, y) in points {
+                    *x *= factor;
+                    *y *= factor;
+                }
+            }
+            ZoneShape::Ring { radius } => *radius *= factor,
+        }
+    }
+
+    /// Outline as line segments `(x1, y1, x2, y2)`, with arcs split into short chords.
+    pub fn outline(&self) -> Vec<(f64, f64, f64, f64)> {
+        match self {
+            ZoneShape::Sector {
+                min_range,
+                max_range,
+                start_bearing,
+                end_bearing,
+            } => {
+                let mut span = normalize_angle(end_bearing - start_bearing);
+                if span == 0.0 {
+                    span = 360.0;
+                }
+                let steps = (span / ARC_STEP).ceil().max(1.0) as usize;
+                let point = |range: f64, step: usize| {
+                    let rad = (start_bearing + span * step as f64 / steps as f64).to_radians();
+                    (range * rad.cos(), range * rad.sin())
+                };
+
+                let mut segments = Vec::new();
+                for range in [*min_range, *max_range] {
+                    if range <= 0.0 {
+                        continue;
+                    }
+                    for step in 0..steps {
+                        let (x1, y1) = point(range, step);
+                        let (x2, y2) = point(range, step + 1);
+                        segments.push((x1, y1, x2, y2));
+                    }
+                }
+                if span < 360.0 {
+                    for step in [0, steps] {
+                        let (x1, y1) = point(*min_range, step);
+                        let (x2, y2) = point(*max_range, step);
+                        segments.push((x1, y1, x2, y2));
+                    }
+                }
+                segments
+            }
+            ZoneShape::Polygon(points) => points
+                .iter()
+                .enumerate()
+                .map(|(i, &(x1, y1))| {
+                    let (x2, y2) = points[(i + 1) % points.len()];
+                    (x1, y1, x2, y2)
+                })
+                .collect(),
+            ZoneShape::Ring { radius } => ZoneShape::Sector {
+                min_range: 0.0,
+                max_range: *radius,
+                start_bearing: 0.0,
+                end_bearing: 0.0,
+            }
+            .outline(),
+        }
+    }
+}
+
+/// Degrees per chord when drawing arcs.
+const ARC_STEP: f64 = 2.0;
+
+/// A named guard or exclusion area.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zone {
+    pub name: String,
+    pub kind: ZoneKind,
+    pub shape: ZoneShape,
+    /// Types that trip the alarm; every type when `None`.
+    pub types: Option<Vec<ObjectType>>,
+    pub enabled: bool,
+    /// Tracks seen inside on the last check, for entry and exit alarms; ordered
+    /// so events for tracks crossing together come out the same way every run.
+    pub(crate) inside: BTreeSet<u32>,
+}
+
+impl Zone {
+    pub fn new(name: impl Into<String>, kind: ZoneKind, shape: ZoneShape) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            shape,
+            types: None,
+            enabled: true,
+            inside: BTreeSet::new(),
+        }
+    }
+
+    /// The danger-close ring around the radar.
+    pub fn danger_close(radius: f64) -> Self {
+        Self::new("Danger close", ZoneKind::Guard, ZoneShape::Ring { radius })
+    }
+
+    pub fn with_types(mut self, types: Vec<ObjectType>) -> Self {
+        self.types = Some(types);
+        self
+    }
+
+    pub fn watches(&self, object_type: ObjectType) -> bool {
+        self.types
+            .as_ref()
+            .is_none_or(|types| types.contains(&object_type))
+    }
+
+    /// Whether anything is inside a guard zone right now.
+    pub fn is_occupied(&self) -> bool {
+        !self.inside.is_empty()
+    }
+}
+
+/// A zone as written in the config file: exactly one of `sector`, `polygon` or `ring`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ZoneSpec {
+    pub name: String,
+    #[serde(default)]
+    pub kind: ZoneKind,
+    pub types: Option<Vec<ObjectType>>,
+    pub sector: Option<SectorSpec>,
+    /// Corners as `[x, y]` pairs.
+    pub polygon: Option<Vec<[f64; 2]>>,
+    /// Radius of a ring around the radar.
+    pub ring: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SectorSpec {
+    #[serde(default)]
+    pub min_range: f64,
+    pub max_range: f64,
+    pub start_bearing: f64,
+    pub end_bearing: f64,
+}
+
+impl ZoneSpec {
+    /// Check the spec and turn it into a zone, or explain what is wrong with it.
+    pub fn build(&self) -> Result<Zone, String> {
+        let shape = match (&self.sector, &self.polygon, self.ring) {
+            (Some(sector), None, None) => {
+                if !(sector.min_range >= 0.0 && sector.max_range > sector.min_range) {
+                    return Err("sector needs 0 <= min_range < max_range".to_string());
+                }
+                ZoneShape::Sector {
+                    min_range: sector.min_range,
+                    max_range: sector.max_range,
+                    start_bearing: normalize_angle(sector.start_bearing),
+                    end_bearing: normalize_angle(sector.end_bearing),
+                }
+            }
+            (None, Some(points), None) => {
+                if points.len() < 3 {
+                    return Err("polygon needs at least 3 points".to_string());
+                }
+                ZoneShape::Polygon(points.iter().map(|&[x, y]| (x, y)).collect())
+            }
+            (None, None, Some(radius)) => {
+                if !(radius > 0.0 && radius.is_finite()) {
+                    return Err("ring must be a positive radius".to_string());
+                }
+                ZoneShape::Ring { radius }
+            }
+            _ => return Err("give exactly one of sector, polygon or ring".to_string()),
+        };
+
+        let zone = Zone::new(self.name.clone(), self.kind, shape);
+        Ok(match &self.types {
+            Some(types) => zone.with_types(types.clone()),
+            None => zone,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sector_wraps_through_zero() {
+        let sector = ZoneShape::Sector {
+            min_range: 100.0,
+            max_range: 200.0,
+            start_bearing: 330.0,
+            end_bearing: 30.0,
+        };
+        let at = |bearing: f64, range: f64| {
+            let rad = bearing.to_radians();
+            sector.contains(range * rad.cos(), range * rad.sin())
+        };
+        assert!(at(0.0, 150.0));
+        assert!(at(340.0, 150.0));
+        assert!(at(20.0, 199.0));
+        assert!(!at(45.0, 150.0));
+        assert!(!at(0.0, 50.0));
+        assert!(!at(0.0, 250.0));
+    }
+
+    #[test]
+    fn test_polygon_contains() {
+        let square = ZoneShape::Polygon(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        assert!(square.contains(5.0, 5.0));
+        assert!(!square.contains(15.0, 5.0));
+        assert!(!square.contains(5.0, -1.0));
+    }
+
+    #[test]
+    fn test_spec_needs_one_shape() {
+        let spec: ZoneSpec = toml::from_str(
+            r#"
+            name = "Both"
+            ring = 50.0
+            polygon = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            spec.build().unwrap_err(),
+            "give exactly one of sector, polygon or ring"
+        );
+    }
+}